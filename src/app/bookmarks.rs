@@ -0,0 +1,94 @@
+//! Bookmarked `remote:path` locations, persisted across sessions.
+
+use crate::error::{LazyFileError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tracing::{debug, warn};
+
+/// Name of the bookmarks file within the XDG config dir.
+const BOOKMARKS_FILE: &str = "bookmarks.toml";
+
+/// Named `remote:path` locations the user can jump back to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Bookmarks {
+    #[serde(flatten)]
+    marks: BTreeMap<String, String>,
+}
+
+impl Bookmarks {
+    /// Load bookmarks from the XDG config dir, returning an empty set if
+    /// the file doesn't exist yet or fails to parse.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).unwrap_or_else(|e| {
+                warn!("Failed to parse bookmarks file: {}", e);
+                Self::default()
+            }),
+            Err(e) => {
+                warn!("Failed to read bookmarks file: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Persist bookmarks to the XDG config dir.
+    ///
+    /// # Errors
+    /// Returns error if the config directory can't be created or written to.
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()
+            .ok_or_else(|| LazyFileError::Config("could not determine config dir".to_string()))?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let contents = toml::to_string_pretty(self)
+            .map_err(|e| LazyFileError::Config(format!("failed to serialize bookmarks: {}", e)))?;
+        std::fs::write(&path, contents)?;
+        Ok(())
+    }
+
+    /// Add or replace a named bookmark pointing at `remote:path`.
+    pub fn add(&mut self, name: impl Into<String>, remote: &str, path: &str) {
+        let name = name.into();
+        let location = format!("{}:{}", remote, path);
+        debug!("Bookmarking '{}' -> {}", name, location);
+        self.marks.insert(name, location);
+    }
+
+    /// Bookmarks in name order, as `(name, remote:path)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.marks.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Number of stored bookmarks.
+    pub fn len(&self) -> usize {
+        self.marks.len()
+    }
+
+    /// True if there are no bookmarks.
+    pub fn is_empty(&self) -> bool {
+        self.marks.is_empty()
+    }
+
+    /// Split a `remote:path` location into its parts.
+    pub fn split_location(location: &str) -> (String, String) {
+        match location.split_once(':') {
+            Some((remote, path)) => (remote.to_string(), path.to_string()),
+            None => (location.to_string(), String::new()),
+        }
+    }
+
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("lazyfile").join(BOOKMARKS_FILE))
+    }
+}
@@ -0,0 +1,128 @@
+//! Incremental fuzzy matching and scoring for list filtering.
+
+/// Bonus applied per pair of consecutive matched characters.
+const CONSECUTIVE_BONUS: i32 = 8;
+/// Bonus applied when a match immediately follows a separator.
+const WORD_BOUNDARY_BONUS: i32 = 6;
+/// Bonus applied when the match starts at the very first character.
+const START_BONUS: i32 = 4;
+/// Penalty applied per character of gap between the first and last match.
+const GAP_PENALTY: i32 = 1;
+
+/// Characters that count as word boundaries for the word-boundary bonus.
+fn is_separator(c: char) -> bool {
+    matches!(c, '/' | '_' | '-' | '.')
+}
+
+/// A successful fuzzy match: the matched char positions (for highlighting)
+/// and a score used to rank surviving candidates, higher is better.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Char indices into the candidate that matched the query, in order.
+    pub positions: Vec<usize>,
+    /// Higher scores rank first; see `fuzzy_match` for how it's computed.
+    pub score: i32,
+}
+
+/// Check whether `query` is a subsequence of `candidate` (case-insensitive),
+/// scoring the match by rewarding consecutive runs, word-boundary and
+/// start-of-string matches, and penalizing the span between the first and
+/// last matched character.
+///
+/// An empty `query` matches everything with a score of 0 and no highlighted
+/// positions.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            positions: Vec::new(),
+            score: 0,
+        });
+    }
+
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate_chars: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut positions = Vec::with_capacity(query_chars.len());
+    let mut qi = 0;
+
+    for (ci, &c) in candidate_chars.iter().enumerate() {
+        if qi < query_chars.len() && c == query_chars[qi] {
+            positions.push(ci);
+            qi += 1;
+        }
+    }
+
+    if qi != query_chars.len() {
+        return None;
+    }
+
+    let score = score_positions(&positions, &candidate_chars);
+    Some(FuzzyMatch { positions, score })
+}
+
+/// Score a set of matched positions within `candidate_chars`.
+fn score_positions(positions: &[usize], candidate_chars: &[char]) -> i32 {
+    let mut score = 0;
+
+    for (i, &pos) in positions.iter().enumerate() {
+        if pos == 0 {
+            score += START_BONUS;
+        } else if is_separator(candidate_chars[pos - 1]) {
+            score += WORD_BOUNDARY_BONUS;
+        }
+
+        if i > 0 && pos == positions[i - 1] + 1 {
+            score += CONSECUTIVE_BONUS;
+        }
+    }
+
+    if let (Some(&first), Some(&last)) = (positions.first(), positions.last()) {
+        let gap = (last - first + 1).saturating_sub(positions.len());
+        score -= gap as i32 * GAP_PENALTY;
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        let m = fuzzy_match("", "anything").unwrap();
+        assert!(m.positions.is_empty());
+        assert_eq!(m.score, 0);
+    }
+
+    #[test]
+    fn test_non_subsequence_does_not_match() {
+        assert!(fuzzy_match("xyz", "abc").is_none());
+    }
+
+    #[test]
+    fn test_case_insensitive_match() {
+        assert!(fuzzy_match("ABC", "abcdef").is_some());
+    }
+
+    #[test]
+    fn test_consecutive_match_scores_higher_than_scattered() {
+        let consecutive = fuzzy_match("abc", "abcxyz").unwrap();
+        let scattered = fuzzy_match("abc", "axbxcx").unwrap();
+        assert!(consecutive.score > scattered.score);
+    }
+
+    #[test]
+    fn test_word_boundary_match_scores_higher() {
+        let boundary = fuzzy_match("f", "my_file").unwrap();
+        let mid = fuzzy_match("i", "my_file").unwrap();
+        assert!(boundary.score > mid.score);
+    }
+
+    #[test]
+    fn test_start_of_string_match_scores_higher() {
+        let at_start = fuzzy_match("m", "main.rs").unwrap();
+        let not_at_start = fuzzy_match("a", "main.rs").unwrap();
+        assert!(at_start.score > not_at_start.score);
+    }
+}
@@ -1,11 +1,13 @@
 //! Keyboard event handling.
 
-use super::state::{App, Panel};
+use super::state::App;
+use crate::config::keymap::key_string;
+use crate::config::{Action, Mode};
 use crate::error::Result;
-use crate::ui::{ConfirmModal, CreateRemoteModal, CreateRemoteMode};
+use crate::ui::{BookmarksModal, ConfirmModal, CreateRemoteModal, CreateRemoteMode};
 use crossterm::event::{KeyCode, KeyEvent};
 use std::collections::HashMap;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Handles keyboard input events.
 pub struct Handler;
@@ -30,35 +32,202 @@ impl Handler {
             return Self::handle_modal_key(app, key).await;
         }
 
-        match key.code {
-            KeyCode::Char('q') => {
+        // If the bookmarks popup is open, handle it
+        if app.bookmarks_modal.is_some() {
+            return Self::handle_bookmarks_key(app, key).await;
+        }
+
+        // If the user is typing a new bookmark's name, handle it
+        if app.new_bookmark_name.is_some() {
+            return Self::handle_bookmark_name_key(app, key);
+        }
+
+        // If an incremental filter is active, handle it
+        if app.focused_pane().filter_query.is_some() {
+            return Self::handle_filter_key(app, key).await;
+        }
+
+        let browsing_remotes = app.focused_pane().remote.is_none();
+        let has_selection = !app.focused_pane().selected_files.is_empty();
+        let action = app.config.keymap.resolve(Mode::Normal, &key_string(&key));
+
+        match action {
+            Some(Action::Quit) => {
                 info!("Quit requested");
                 app.running = false;
             }
-            KeyCode::Char('a') if matches!(app.focused_panel, Panel::Remotes) => {
+            Some(Action::StartFilter) => {
+                debug!("Starting incremental filter");
+                app.focused_pane_mut().filter_query = Some(String::new());
+            }
+            Some(Action::AddRemote) if browsing_remotes => {
                 debug!("Opening create remote modal");
                 app.create_remote_modal = Some(CreateRemoteModal::new(CreateRemoteMode::Create));
             }
-            KeyCode::Char('d') if matches!(app.focused_panel, Panel::Remotes) => {
+            Some(Action::Delete) if browsing_remotes => {
                 Self::handle_delete_remote(app);
             }
-            KeyCode::Char('e') if matches!(app.focused_panel, Panel::Remotes) => {
+            Some(Action::Delete) if !browsing_remotes && has_selection => {
+                Self::handle_delete_selected_files(app);
+            }
+            Some(Action::EditRemote) if browsing_remotes => {
                 Self::handle_edit_remote(app).await?;
             }
-            KeyCode::Char('j') | KeyCode::Down => {
+            Some(Action::ToggleSelection) if !browsing_remotes => {
+                if let Some(index) = app.focused_pane().selected_file_index() {
+                    app.focused_pane_mut().toggle_file_selection(index);
+                }
+            }
+            Some(Action::CopySelection) if !browsing_remotes => {
+                info!("Copying selection to other pane");
+                app.copy_selection_to_other_pane().await?;
+            }
+            Some(Action::MoveSelection) if !browsing_remotes => {
+                info!("Moving selection to other pane");
+                app.move_selection_to_other_pane().await?;
+            }
+            Some(Action::SelectAll) if !browsing_remotes => {
+                debug!("Selecting all files");
+                app.focused_pane_mut().select_all_files();
+            }
+            Some(Action::BookmarkPrompt) if !browsing_remotes => {
+                debug!("Prompting for bookmark name");
+                app.new_bookmark_name = Some(String::new());
+            }
+            Some(Action::OpenBookmarks) => {
+                debug!("Opening bookmarks popup");
+                app.bookmarks_modal = Some(BookmarksModal::new());
+            }
+            Some(Action::TogglePreview) => {
+                app.preview_visible = !app.preview_visible;
+                debug!("Preview pane visible: {}", app.preview_visible);
+            }
+            Some(Action::Refresh) if !browsing_remotes => {
+                Self::handle_refresh(app).await?;
+            }
+            Some(Action::CycleSort) if !browsing_remotes => {
+                app.focused_pane_mut().cycle_sort_mode();
+                Self::reset_selection(app);
+            }
+            Some(Action::ToggleSortDirection) if !browsing_remotes => {
+                app.focused_pane_mut().toggle_sort_direction();
+                Self::reset_selection(app);
+            }
+            Some(Action::CancelJob) => {
+                Self::handle_cancel_job(app).await?;
+            }
+            Some(Action::NavigateDown) => {
                 app.navigate_down();
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            Some(Action::NavigateUp) => {
                 app.navigate_up();
             }
-            KeyCode::Tab => {
+            Some(Action::SwitchPane) => {
                 app.switch_panel();
             }
+            Some(Action::Open) => {
+                Self::handle_enter(app).await?;
+            }
+            Some(Action::Back) => {
+                Self::handle_backspace(app).await?;
+            }
+            _ => {}
+        }
+        app.update_preview().await?;
+        Ok(())
+    }
+
+    /// Handle keyboard input while an incremental filter is active.
+    async fn handle_filter_key(app: &mut App, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                debug!("Clearing filter");
+                app.focused_pane_mut().filter_query = None;
+                Self::reset_selection(app);
+            }
+            KeyCode::Char(c) => {
+                if let Some(ref mut query) = app.focused_pane_mut().filter_query {
+                    query.push(c);
+                }
+                Self::reset_selection(app);
+            }
+            KeyCode::Backspace => {
+                if let Some(ref mut query) = app.focused_pane_mut().filter_query {
+                    query.pop();
+                }
+                Self::reset_selection(app);
+            }
+            KeyCode::Up => app.navigate_up(),
+            KeyCode::Down => app.navigate_down(),
             KeyCode::Enter => {
                 Self::handle_enter(app).await?;
+                app.focused_pane_mut().filter_query = None;
+                Self::reset_selection(app);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Reset the focused pane's selection to the top of the filtered list.
+    fn reset_selection(app: &mut App) {
+        app.focused_pane_mut().selected = 0;
+    }
+
+    /// Handle keyboard input while naming a new bookmark.
+    fn handle_bookmark_name_key(app: &mut App, key: KeyEvent) -> Result<()> {
+        let Some(ref mut name) = app.new_bookmark_name else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                debug!("Cancelling bookmark creation");
+                app.new_bookmark_name = None;
+            }
+            KeyCode::Char(c) => {
+                name.push(c);
             }
             KeyCode::Backspace => {
-                Self::handle_backspace(app).await?;
+                name.pop();
+            }
+            KeyCode::Enter => {
+                if let Some(name) = app.new_bookmark_name.take() {
+                    if !name.is_empty() {
+                        app.add_bookmark(name);
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle keyboard input while the bookmarks popup is open.
+    async fn handle_bookmarks_key(app: &mut App, key: KeyEvent) -> Result<()> {
+        let Some(ref mut modal) = app.bookmarks_modal else {
+            return Ok(());
+        };
+        let len = app.bookmarks.len();
+
+        match key.code {
+            KeyCode::Esc => {
+                debug!("Closing bookmarks popup");
+                app.bookmarks_modal = None;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                modal.next(len);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                modal.prev(len);
+            }
+            KeyCode::Enter => {
+                let selected = modal.selected;
+                app.bookmarks_modal = None;
+                if let Some((_, location)) = app.bookmarks.iter().nth(selected) {
+                    let location = location.to_string();
+                    app.jump_to_bookmark(&location).await?;
+                }
             }
             _ => {}
         }
@@ -67,30 +236,81 @@ impl Handler {
 
     /// Handle keyboard input while modal is open.
     async fn handle_modal_key(app: &mut App, key: KeyEvent) -> Result<()> {
-        if let Some(ref mut modal) = app.create_remote_modal {
-            match key.code {
-                KeyCode::Esc => {
-                    debug!("Closing create remote modal");
-                    app.create_remote_modal = None;
-                }
-                KeyCode::Tab => {
+        match key.code {
+            KeyCode::Esc => {
+                debug!("Closing create remote modal");
+                app.create_remote_modal = None;
+            }
+            KeyCode::Tab => {
+                let was_on_type = Self::modal_focus_is_type(app);
+                if let Some(modal) = app.create_remote_modal.as_mut() {
                     modal.next_field();
                 }
-                KeyCode::BackTab => {
+                if was_on_type {
+                    Self::refresh_provider_fields(app).await?;
+                }
+            }
+            KeyCode::BackTab => {
+                let was_on_type = Self::modal_focus_is_type(app);
+                if let Some(modal) = app.create_remote_modal.as_mut() {
                     modal.prev_field();
                 }
-                KeyCode::Char(c) => {
+                if was_on_type {
+                    Self::refresh_provider_fields(app).await?;
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(modal) = app.create_remote_modal.as_mut() {
                     modal.input_char(c);
                     modal.error = None;
                 }
-                KeyCode::Backspace => {
+            }
+            KeyCode::Backspace => {
+                if let Some(modal) = app.create_remote_modal.as_mut() {
                     modal.backspace();
                     modal.error = None;
                 }
-                KeyCode::Enter => {
-                    Self::handle_modal_submit(app).await?;
+            }
+            KeyCode::Enter => {
+                Self::handle_modal_submit(app).await?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// True if the create/edit remote modal is currently focused on the
+    /// Type field, i.e. the field whose provider schema drives `fields`.
+    fn modal_focus_is_type(app: &App) -> bool {
+        app.create_remote_modal
+            .as_ref()
+            .is_some_and(|m| m.focus == CreateRemoteModal::TYPE_FIELD)
+    }
+
+    /// Fetch the provider schema for the modal's current `remote_type` and
+    /// rebuild its dynamic fields to match the selected backend.
+    async fn refresh_provider_fields(app: &mut App) -> Result<()> {
+        let Some(remote_type) = app
+            .create_remote_modal
+            .as_ref()
+            .map(|m| m.remote_type.clone())
+        else {
+            return Ok(());
+        };
+        if remote_type.is_empty() {
+            return Ok(());
+        }
+
+        match app.client.providers().await {
+            Ok(providers) => {
+                if let Some(provider) = providers.into_iter().find(|p| p.name == remote_type) {
+                    if let Some(modal) = app.create_remote_modal.as_mut() {
+                        modal.apply_provider(&provider);
+                    }
                 }
-                _ => {}
+            }
+            Err(e) => {
+                warn!("Failed to fetch provider schema for '{}': {}", remote_type, e);
             }
         }
         Ok(())
@@ -101,16 +321,18 @@ impl Handler {
         if let Some(modal) = app.create_remote_modal.take() {
             if !modal.is_valid() {
                 app.create_remote_modal = Some(CreateRemoteModal {
-                    error: Some("Name and Type are required".to_string()),
+                    error: Some("Name, Type, and all required fields are needed".to_string()),
                     ..modal
                 });
                 return Ok(());
             }
 
-            let mut params = HashMap::new();
-            if !modal.path.is_empty() {
-                params.insert("path".to_string(), modal.path.clone());
-            }
+            let params: HashMap<String, String> = modal
+                .fields
+                .iter()
+                .filter(|f| !f.value.is_empty())
+                .map(|f| (f.key.clone(), f.value.clone()))
+                .collect();
 
             let name = modal.name.clone();
             let remote_type = modal.remote_type.clone();
@@ -146,7 +368,7 @@ impl Handler {
 
     /// Handle delete remote - open confirmation modal.
     fn handle_delete_remote(app: &mut App) {
-        if let Some(remote) = app.remotes.get(app.remotes_selected) {
+        if let Some(remote) = app.selected_remote(app.focused_panel) {
             debug!("Opening delete confirmation for: {}", remote);
             app.pending_delete_remote = Some(remote.clone());
             app.confirm_modal = Some(ConfirmModal::new(
@@ -156,103 +378,151 @@ impl Handler {
         }
     }
 
+    /// Handle batch delete of the selected files - open confirmation modal.
+    fn handle_delete_selected_files(app: &mut App) {
+        let count = app.focused_pane().selected_files.len();
+        debug!("Opening delete confirmation for {} selected files", count);
+        app.pending_delete_files = true;
+        app.confirm_modal = Some(ConfirmModal::new(
+            "Delete Files",
+            format!("Delete {} items?", count),
+        ));
+    }
+
     /// Handle confirmation modal input.
     async fn handle_confirm_key(app: &mut App, key: KeyEvent) -> Result<()> {
-        if let Some(ref mut modal) = app.confirm_modal {
-            match key.code {
-                KeyCode::Esc => {
-                    debug!("Cancelling delete");
-                    app.confirm_modal = None;
-                    app.pending_delete_remote = None;
-                }
-                KeyCode::Tab | KeyCode::Right | KeyCode::Left => {
-                    modal.toggle();
-                }
-                KeyCode::Char(c) if c == 'y' || c == 'n' => {
+        // 'y'/'n' pick a side directly rather than naming an action, so they
+        // stay outside the keymap table.
+        if let KeyCode::Char(c) = key.code {
+            if c == 'y' || c == 'n' {
+                if let Some(ref mut modal) = app.confirm_modal {
                     let confirmed = c == 'y';
                     if confirmed != modal.is_confirmed() {
                         modal.toggle();
                     }
                 }
-                KeyCode::Enter => {
-                    if modal.is_confirmed()
-                        && let Some(remote) = app.pending_delete_remote.take()
-                    {
+                return Ok(());
+            }
+        }
+
+        let action = app.config.keymap.resolve(Mode::Confirm, &key_string(&key));
+        match action {
+            Some(Action::Cancel) => {
+                debug!("Cancelling delete");
+                app.confirm_modal = None;
+                app.pending_delete_remote = None;
+                app.pending_delete_files = false;
+            }
+            Some(Action::Toggle) => {
+                if let Some(ref mut modal) = app.confirm_modal {
+                    modal.toggle();
+                }
+            }
+            Some(Action::Confirm) => {
+                let confirmed = app.confirm_modal.as_ref().is_some_and(|m| m.is_confirmed());
+                if confirmed {
+                    if let Some(remote) = app.pending_delete_remote.take() {
                         info!("Deleting remote: {}", remote);
                         app.client.delete_remote(&remote).await?;
                         app.load_remotes().await?;
+                    } else if app.pending_delete_files {
+                        info!(
+                            "Deleting {} selected files",
+                            app.focused_pane().selected_files.len()
+                        );
+                        app.delete_selected_files().await?;
                     }
-                    app.confirm_modal = None;
                 }
-                _ => {}
+                app.pending_delete_files = false;
+                app.confirm_modal = None;
             }
+            _ => {}
         }
         Ok(())
     }
 
     /// Handle edit remote.
     async fn handle_edit_remote(app: &mut App) -> Result<()> {
-        if let Some(remote) = app.remotes.get(app.remotes_selected) {
+        if let Some(remote) = app.selected_remote(app.focused_panel) {
             info!("Editing remote: {}", remote);
             let modal = CreateRemoteModal::new(CreateRemoteMode::Edit)
                 .with_name(remote.clone())
                 .with_type("local".to_string());
             app.create_remote_modal = Some(modal);
+            Self::refresh_provider_fields(app).await?;
         }
         Ok(())
     }
 
-    /// Handle Enter key: select remote or open directory.
+    /// Handle manual refresh: bypass the listing cache for the focused
+    /// pane's current path.
+    async fn handle_refresh(app: &mut App) -> Result<()> {
+        info!("Refreshing current directory");
+        app.refresh_files().await
+    }
+
+    /// Handle the cancel-job key: stop the oldest in-flight background job,
+    /// if any. A no-op when nothing is running.
+    async fn handle_cancel_job(app: &mut App) -> Result<()> {
+        let Some(id) = app.jobs.first().map(|job| job.id) else {
+            return Ok(());
+        };
+        info!("Cancelling job {}", id);
+        app.stop_job(id).await
+    }
+
+    /// Handle Enter key: select remote or open directory in the focused pane.
     async fn handle_enter(app: &mut App) -> Result<()> {
-        match app.focused_panel {
-            Panel::Remotes => {
-                if let Some(remote) = app.remotes.get(app.remotes_selected) {
-                    info!("Selecting remote: {}", remote);
-                    app.current_remote = Some(remote.clone());
-                    app.current_path = String::new();
-                    app.load_files().await?;
-                    app.focused_panel = Panel::Files;
-                }
+        let panel = app.focused_panel;
+        if app.pane(panel).remote.is_none() {
+            if let Some(remote) = app.selected_remote(panel) {
+                let remote = remote.clone();
+                info!("Selecting remote: {}", remote);
+                let pane = app.pane_mut(panel);
+                pane.remote = Some(remote);
+                pane.path = String::new();
+                app.load_files(panel).await?;
             }
-            Panel::Files => {
-                if let Some(item) = app.files.get(app.files_selected)
-                    && item.is_dir()
-                {
-                    let name = item.name();
-                    debug!("Opening directory: {}", name);
-                    if app.current_path.is_empty() {
-                        app.current_path = format!("/{}", name);
-                    } else {
-                        app.current_path = format!("{}/{}", app.current_path, name);
-                    }
-                    app.load_files().await?;
+        } else if let Some(item) = app.pane(panel).selected_file() {
+            if item.is_dir() {
+                let name = item.name().to_string();
+                debug!("Opening directory: {}", name);
+                let pane = app.pane_mut(panel);
+                if pane.path.is_empty() {
+                    pane.path = format!("/{}", name);
+                } else {
+                    pane.path = format!("{}/{}", pane.path, name);
                 }
+                app.load_files(panel).await?;
             }
         }
         Ok(())
     }
 
-    /// Handle Backspace key: go to parent directory or back to remotes.
+    /// Handle Backspace key: go to parent directory or back to remotes, in
+    /// the focused pane.
     async fn handle_backspace(app: &mut App) -> Result<()> {
-        match app.focused_panel {
-            Panel::Files => {
-                if !app.current_path.is_empty() {
-                    if let Some(last_slash) = app.current_path.rfind('/') {
-                        debug!("Going back from {}", app.current_path);
-                        app.current_path.truncate(last_slash);
-                    } else {
-                        app.current_path.clear();
-                    }
-                    app.load_files().await?;
-                } else {
-                    info!("Going back to remotes");
-                    app.current_remote = None;
-                    app.focused_panel = Panel::Remotes;
-                    app.files.clear();
-                }
-            }
-            Panel::Remotes => {}
+        let panel = app.focused_panel;
+        let pane = app.pane(panel);
+        if pane.remote.is_none() {
+            return Ok(());
         }
-        Ok(())
+
+        if pane.path.is_empty() {
+            info!("Going back to remotes");
+            let pane = app.pane_mut(panel);
+            pane.remote = None;
+            pane.files.clear();
+            return Ok(());
+        }
+
+        debug!("Going back from {}", pane.path);
+        let pane = app.pane_mut(panel);
+        if let Some(last_slash) = pane.path.rfind('/') {
+            pane.path.truncate(last_slash);
+        } else {
+            pane.path.clear();
+        }
+        app.load_files(panel).await
     }
 }
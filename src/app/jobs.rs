@@ -0,0 +1,155 @@
+//! Background transfer job tracking.
+
+use crate::error::Result;
+use crate::rclone::RcloneClient;
+use std::collections::HashMap;
+use tracing::{debug, warn};
+
+/// A transfer submitted to the rclone daemon that is tracked in the background.
+#[derive(Debug, Clone)]
+pub struct Job {
+    /// Rclone jobid returned by the `_async` request.
+    pub id: u64,
+    /// Remote-relative path of the file being transferred, as rclone
+    /// reports it in `core/stats`' `transferring[].name` — used to match
+    /// this job against its live throughput, not shown to the user.
+    pub name: String,
+    /// Human-readable description shown in the transfers panel.
+    pub description: String,
+    /// Stats group this job was submitted under, if any. Jobs that are part
+    /// of the same multi-file operation (e.g. one `delete_selected_files`
+    /// call) share a group so their stats can be queried together, without
+    /// colliding with unrelated transfers that happen to touch a
+    /// same-named file.
+    pub group: Option<String>,
+    /// True once the daemon reports the job has stopped running.
+    pub finished: bool,
+    /// True if the job finished without error.
+    pub success: bool,
+    /// Error message from the daemon, if any.
+    pub error: String,
+    /// Bytes transferred so far.
+    pub bytes: i64,
+    /// Total bytes expected, 0 if unknown.
+    pub total_bytes: i64,
+    /// Current transfer speed in bytes/sec.
+    pub speed: f64,
+}
+
+impl Job {
+    /// Create a new, not-yet-polled job.
+    pub fn new(
+        id: u64,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        group: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            description: description.into(),
+            group,
+            finished: false,
+            success: false,
+            error: String::new(),
+            bytes: 0,
+            total_bytes: 0,
+            speed: 0.0,
+        }
+    }
+
+    /// Percentage complete, 0.0 if the total size isn't known yet.
+    pub fn percent(&self) -> f64 {
+        if self.total_bytes <= 0 {
+            0.0
+        } else {
+            (self.bytes as f64 / self.total_bytes as f64) * 100.0
+        }
+    }
+}
+
+/// Poll every in-flight job for status and throughput, removing finished ones.
+///
+/// If any job finished with an error, the message of the last such job is
+/// returned so the caller can surface it (e.g. in the status bar) before the
+/// job is dropped.
+///
+/// Daemon failures don't produce an error: a failed `job/status` poll is
+/// logged and the job is kept for the next tick, and a failed `core/stats`
+/// call leaves that tick's throughput figures unchanged.
+pub async fn poll_jobs(client: &RcloneClient, jobs: &mut Vec<Job>) -> Result<Option<String>> {
+    if jobs.is_empty() {
+        return Ok(None);
+    }
+
+    // Jobs in the same group are queried together so their progress doesn't
+    // get mixed up with an unrelated, concurrently-running group that
+    // happens to transfer a same-named file. Ungrouped jobs fall back to the
+    // daemon's default (ungrouped) stats. Either way a job is matched to its
+    // transfer by `name`.
+    let mut grouped_stats = HashMap::new();
+    for group in jobs.iter().filter_map(|job| job.group.as_deref()) {
+        if !grouped_stats.contains_key(group) {
+            let stats = client.core_stats(Some(group)).await.unwrap_or_default();
+            grouped_stats.insert(group.to_string(), stats);
+        }
+    }
+    let ungrouped_stats = if jobs.iter().any(|job| job.group.is_none()) {
+        client.core_stats(None).await.unwrap_or_default()
+    } else {
+        Default::default()
+    };
+
+    let mut last_error = None;
+
+    for job in jobs.iter_mut() {
+        match client.job_status(job.id).await {
+            Ok(status) => {
+                job.finished = status.finished;
+                job.success = status.success;
+                job.error = status.error;
+            }
+            Err(e) => {
+                warn!("Failed to poll job {}: {}", job.id, e);
+            }
+        }
+
+        let stats = match &job.group {
+            Some(group) => grouped_stats.get(group).unwrap_or(&ungrouped_stats),
+            None => &ungrouped_stats,
+        };
+        if let Some(transfer) = stats.transferring.iter().find(|t| t.name == job.name) {
+            job.bytes = transfer.bytes;
+            job.total_bytes = transfer.size;
+            job.speed = transfer.speed;
+        }
+
+        if job.finished && !job.success {
+            last_error = Some(job.error.clone());
+        }
+    }
+
+    jobs.retain(|job| {
+        if job.finished {
+            debug!(
+                "Job {} ({}) finished, success: {}",
+                job.id, job.description, job.success
+            );
+        }
+        !job.finished
+    });
+
+    Ok(last_error)
+}
+
+/// Cancel an in-flight job and drop it from `jobs` immediately, rather than
+/// waiting for the next `poll_jobs` tick to notice it stopped.
+///
+/// # Errors
+/// Returns error if the rclone daemon is unreachable.
+pub async fn stop_job(client: &RcloneClient, jobs: &mut Vec<Job>, id: u64) -> Result<()> {
+    client.job_stop(id).await?;
+    jobs.retain(|job| job.id != id);
+    debug!("Stopped job {}", id);
+    Ok(())
+}
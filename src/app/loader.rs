@@ -0,0 +1,72 @@
+//! Background directory-listing loads, so a slow `list_files` call against
+//! the rclone daemon doesn't block the key handler.
+
+use super::state::Panel;
+use crate::error::{LazyFileError, Result};
+use crate::rclone::{FileItem, RcloneClient};
+use tokio::task::JoinHandle;
+
+/// A directory listing running on a background task for `panel`.
+#[derive(Debug)]
+pub struct PendingLoad {
+    /// Pane this load will populate once it finishes.
+    pub panel: Panel,
+    /// Remote being listed.
+    pub remote: String,
+    /// Path within `remote` being listed.
+    pub path: String,
+    handle: JoinHandle<Result<Vec<FileItem>>>,
+}
+
+impl PendingLoad {
+    /// Spawn a background listing of `remote:path` for `panel`.
+    pub fn spawn(panel: Panel, client: RcloneClient, remote: String, path: String) -> Self {
+        let handle = {
+            let remote = remote.clone();
+            let path = path.clone();
+            tokio::spawn(async move { client.list_files(&remote, &path).await })
+        };
+        Self {
+            panel,
+            remote,
+            path,
+            handle,
+        }
+    }
+
+    /// Whether the background task has finished running.
+    fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+}
+
+/// Remove every finished load from `pending`, returning each one's panel,
+/// remote, path, and listing result so the caller can apply it to the
+/// corresponding pane and listing cache.
+///
+/// Awaiting an already-finished `JoinHandle` resolves immediately, so this
+/// never blocks the caller.
+pub async fn drain_finished(
+    pending: &mut Vec<PendingLoad>,
+) -> Vec<(Panel, String, String, Result<Vec<FileItem>>)> {
+    let mut finished_at = Vec::new();
+    for (i, load) in pending.iter().enumerate() {
+        if load.is_finished() {
+            finished_at.push(i);
+        }
+    }
+
+    let mut results = Vec::with_capacity(finished_at.len());
+    for i in finished_at.into_iter().rev() {
+        let load = pending.remove(i);
+        let result = match load.handle.await {
+            Ok(result) => result,
+            Err(e) => Err(LazyFileError::RcloneApi(format!(
+                "Listing task failed: {}",
+                e
+            ))),
+        };
+        results.push((load.panel, load.remote, load.path, result));
+    }
+    results
+}
@@ -1,6 +1,11 @@
 //! Application state and event handling.
 
+pub mod bookmarks;
+pub mod filter;
 pub mod handler;
+pub mod jobs;
+pub mod loader;
+pub mod pane;
 pub mod state;
 
 pub use handler::Handler;
@@ -0,0 +1,240 @@
+//! Per-pane navigation state.
+//!
+//! Each [`Pane`] is either browsing the shared remotes list (`remote` is
+//! `None`) or browsing files within a chosen remote and path. The two panes
+//! that make up the UI are otherwise fully independent: each keeps its own
+//! selection, filter, and sort state.
+
+use crate::app::filter;
+use crate::rclone::NavigationItem;
+use std::collections::HashSet;
+use tracing::debug;
+
+/// Field used to order a pane's files list. Directories always sort before
+/// files regardless of the active mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortMode {
+    /// Sort by name (the default).
+    Name,
+    /// Sort by file size.
+    Size,
+    /// Sort by modification time.
+    ModTime,
+}
+
+/// One of the two independent navigation panes.
+#[derive(Debug)]
+pub struct Pane {
+    /// Remote currently open in this pane, `None` while browsing the
+    /// remotes list.
+    pub remote: Option<String>,
+    /// Current path within `remote`.
+    pub path: String,
+    /// Files and directories at `path` (empty while browsing the remotes
+    /// list).
+    pub files: Vec<NavigationItem>,
+    /// Selected index into whichever filtered list is currently shown.
+    pub selected: usize,
+    /// Indices into `files` marked for a batch operation, within the
+    /// current remote/path.
+    pub selected_files: HashSet<usize>,
+    /// Incremental fuzzy filter query, if active.
+    pub filter_query: Option<String>,
+    /// Field `files` is currently ordered by.
+    pub sort_mode: SortMode,
+    /// Whether the files sort is ascending (true) or descending (false).
+    pub sort_ascending: bool,
+}
+
+impl Pane {
+    /// Create a new pane, browsing the remotes list.
+    pub fn new() -> Self {
+        Self {
+            remote: None,
+            path: String::new(),
+            files: Vec::new(),
+            selected: 0,
+            selected_files: HashSet::new(),
+            filter_query: None,
+            sort_mode: SortMode::Name,
+            sort_ascending: true,
+        }
+    }
+
+    /// Indices into `files` that match the active filter query, ranked by
+    /// descending fuzzy match score. Ordered by the active
+    /// `sort_mode`/`sort_ascending` (directories first) when no filter is
+    /// active.
+    pub fn filtered_file_indices(&self) -> Vec<usize> {
+        match &self.filter_query {
+            Some(query) if !query.is_empty() => {
+                let mut scored: Vec<(usize, i32)> = self
+                    .files
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, item)| {
+                        filter::fuzzy_match(query, item.name()).map(|m| (i, m.score))
+                    })
+                    .collect();
+                scored.sort_by(|a, b| b.1.cmp(&a.1));
+                scored.into_iter().map(|(i, _)| i).collect()
+            }
+            _ => {
+                let mut indices: Vec<usize> = (0..self.files.len()).collect();
+                indices.sort_by(|&a, &b| self.compare_files(a, b));
+                indices
+            }
+        }
+    }
+
+    /// Compare two files by index for sorting: directories always sort
+    /// before files, then by the active `sort_mode`/`sort_ascending`.
+    fn compare_files(&self, a: usize, b: usize) -> std::cmp::Ordering {
+        let (a, b) = (&self.files[a], &self.files[b]);
+        let dir_order = b.is_dir().cmp(&a.is_dir());
+        if dir_order != std::cmp::Ordering::Equal {
+            return dir_order;
+        }
+
+        let ordering = match self.sort_mode {
+            SortMode::Name => a.name().cmp(b.name()),
+            SortMode::Size => a.size().cmp(&b.size()),
+            SortMode::ModTime => a.mod_time().cmp(b.mod_time()),
+        };
+        if self.sort_ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    }
+
+    /// The index into `files` of the currently highlighted item, accounting
+    /// for an active filter.
+    pub fn selected_file_index(&self) -> Option<usize> {
+        self.filtered_file_indices().get(self.selected).copied()
+    }
+
+    /// The file currently highlighted, accounting for an active filter.
+    pub fn selected_file(&self) -> Option<&NavigationItem> {
+        self.files.get(self.selected_file_index()?)
+    }
+
+    /// Toggle selection of the file at `index` within `files`.
+    pub fn toggle_file_selection(&mut self, index: usize) {
+        if !self.selected_files.remove(&index) {
+            self.selected_files.insert(index);
+        }
+    }
+
+    /// Select every file currently visible under the active filter.
+    pub fn select_all_files(&mut self) {
+        self.selected_files = self.filtered_file_indices().into_iter().collect();
+    }
+
+    /// Cycle the files sort field: Name -> Size -> ModTime -> Name.
+    pub fn cycle_sort_mode(&mut self) {
+        self.sort_mode = match self.sort_mode {
+            SortMode::Name => SortMode::Size,
+            SortMode::Size => SortMode::ModTime,
+            SortMode::ModTime => SortMode::Name,
+        };
+        debug!("Sorting files by {:?}", self.sort_mode);
+    }
+
+    /// Reverse the files sort direction.
+    pub fn toggle_sort_direction(&mut self) {
+        self.sort_ascending = !self.sort_ascending;
+        debug!("Sort ascending: {}", self.sort_ascending);
+    }
+
+    /// Move the selection down within a list of `len` visible items.
+    pub fn navigate_down(&mut self, len: usize) {
+        if self.selected < len.saturating_sub(1) {
+            self.selected += 1;
+        }
+    }
+
+    /// Move the selection up.
+    pub fn navigate_up(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+        }
+    }
+}
+
+impl Default for Pane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rclone::FileItem;
+
+    fn file(name: &str) -> NavigationItem {
+        NavigationItem::File(FileItem {
+            name: name.to_string(),
+            size: 100,
+            mod_time: "2024-01-01T00:00:00Z".to_string(),
+            is_dir: false,
+        })
+    }
+
+    #[test]
+    fn test_pane_new_browses_remotes() {
+        let pane = Pane::new();
+        assert!(pane.remote.is_none());
+        assert_eq!(pane.path, "");
+        assert!(pane.files.is_empty());
+        assert_eq!(pane.selected, 0);
+        assert!(pane.selected_files.is_empty());
+        assert!(pane.filter_query.is_none());
+        assert_eq!(pane.sort_mode, SortMode::Name);
+        assert!(pane.sort_ascending);
+    }
+
+    #[test]
+    fn test_navigate_down_stops_at_end() {
+        let mut pane = Pane::new();
+        pane.files = vec![file("a"), file("b")];
+
+        pane.navigate_down(pane.files.len());
+        assert_eq!(pane.selected, 1);
+
+        pane.navigate_down(pane.files.len());
+        assert_eq!(pane.selected, 1);
+    }
+
+    #[test]
+    fn test_navigate_up_stops_at_start() {
+        let mut pane = Pane::new();
+        pane.selected = 1;
+
+        pane.navigate_up();
+        assert_eq!(pane.selected, 0);
+
+        pane.navigate_up();
+        assert_eq!(pane.selected, 0);
+    }
+
+    #[test]
+    fn test_toggle_file_selection() {
+        let mut pane = Pane::new();
+        pane.toggle_file_selection(0);
+        assert!(pane.selected_files.contains(&0));
+
+        pane.toggle_file_selection(0);
+        assert!(!pane.selected_files.contains(&0));
+    }
+
+    #[test]
+    fn test_select_all_files() {
+        let mut pane = Pane::new();
+        pane.files = vec![file("a"), file("b")];
+
+        pane.select_all_files();
+        assert_eq!(pane.selected_files.len(), 2);
+    }
+}
@@ -1,17 +1,25 @@
 //! Application state management.
 
+use crate::app::bookmarks::Bookmarks;
+use crate::app::jobs::Job;
+use crate::app::loader::{self, PendingLoad};
+use crate::app::pane::Pane;
+use crate::config::{Config, Mode, AUTO_REFRESH_INTERVAL};
 use crate::error::Result;
-use crate::rclone::{NavigationItem, RcloneClient};
-use crate::ui::{ConfirmModal, CreateRemoteModal};
-use tracing::{debug, info};
+use crate::rclone::{commands, ListingCache, NavigationItem, RcloneClient};
+use crate::ui::{
+    BookmarksModal, ConfirmModal, CreateRemoteModal, PreviewContent, PREVIEW_BYTE_LIMIT,
+};
+use std::time::Instant;
+use tracing::{debug, info, warn};
 
-/// Represents the focused panel in the UI.
+/// Identifies one of the two navigation panes.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Panel {
-    /// Remote list on the left.
-    Remotes,
-    /// Files list on the right.
-    Files,
+    /// Left pane.
+    Left,
+    /// Right pane.
+    Right,
 }
 
 /// Main application state.
@@ -19,19 +27,13 @@ pub enum Panel {
 pub struct App {
     /// RcloneClient for API communication.
     pub client: RcloneClient,
-    /// List of configured remotes.
+    /// List of configured remotes, shared by both panes.
     pub remotes: Vec<String>,
-    /// Currently selected remote.
-    pub current_remote: Option<String>,
-    /// Current path within the remote.
-    pub current_path: String,
-    /// Files and directories in current path.
-    pub files: Vec<NavigationItem>,
-    /// Selected index in remotes list.
-    pub remotes_selected: usize,
-    /// Selected index in files list.
-    pub files_selected: usize,
-    /// Currently focused panel.
+    /// Left navigation pane.
+    pub left: Pane,
+    /// Right navigation pane.
+    pub right: Pane,
+    /// Currently focused pane.
     pub focused_panel: Panel,
     /// Whether the app should continue running.
     pub running: bool,
@@ -41,6 +43,39 @@ pub struct App {
     pub confirm_modal: Option<ConfirmModal>,
     /// Remote name being deleted (used for confirmation).
     pub pending_delete_remote: Option<String>,
+    /// True while `confirm_modal` is open for a batch delete of the focused
+    /// pane's `selected_files`, rather than a single remote deletion.
+    pub pending_delete_files: bool,
+    /// Background transfers submitted to the rclone daemon.
+    pub jobs: Vec<Job>,
+    /// Error from the most recently finished job, shown in the status bar.
+    pub job_error: Option<String>,
+    /// Contents of the currently selected file, shown in the preview pane.
+    pub preview: PreviewContent,
+    /// Whether the preview pane is currently shown.
+    pub preview_visible: bool,
+    /// `(focused_pane's remote:path, selected file index)` for which
+    /// `preview` was last fetched.
+    preview_key: Option<(String, usize)>,
+    /// Bookmarked `remote:path` locations.
+    pub bookmarks: Bookmarks,
+    /// Open bookmarks popup, if any.
+    pub bookmarks_modal: Option<BookmarksModal>,
+    /// In-progress name for a new bookmark, while the user is typing it.
+    pub new_bookmark_name: Option<String>,
+    /// Cached directory listings, keyed by remote and path, shared by both
+    /// panes. The only listing cache in the app: `RcloneClient::list_files`
+    /// itself is uncached, so every mutation only has to invalidate here.
+    listing_cache: ListingCache,
+    /// User-configurable key bindings.
+    pub config: Config,
+    /// Directory listings currently running on background tasks.
+    pending_loads: Vec<PendingLoad>,
+    /// True while a background directory listing is in flight, shown as a
+    /// spinner in the status bar.
+    pub loading: bool,
+    /// Last time `auto_refresh_tick` silently reloaded the open panes.
+    last_auto_refresh: Instant,
 }
 
 impl App {
@@ -49,88 +84,601 @@ impl App {
         Self {
             client,
             remotes: Vec::new(),
-            current_remote: None,
-            current_path: String::new(),
-            files: Vec::new(),
-            remotes_selected: 0,
-            files_selected: 0,
-            focused_panel: Panel::Remotes,
+            left: Pane::new(),
+            right: Pane::new(),
+            focused_panel: Panel::Left,
             running: true,
             create_remote_modal: None,
             confirm_modal: None,
             pending_delete_remote: None,
+            pending_delete_files: false,
+            jobs: Vec::new(),
+            job_error: None,
+            preview: PreviewContent::Empty,
+            preview_visible: true,
+            preview_key: None,
+            bookmarks: Bookmarks::load(),
+            bookmarks_modal: None,
+            new_bookmark_name: None,
+            listing_cache: ListingCache::new(),
+            config: Config::load(),
+            pending_loads: Vec::new(),
+            loading: false,
+            last_auto_refresh: Instant::now(),
         }
     }
 
+    /// The input mode implied by the app's current state, used to resolve
+    /// pressed keys through `config.keymap`.
+    pub fn current_mode(&self) -> Mode {
+        if self.confirm_modal.is_some() {
+            Mode::Confirm
+        } else if self.create_remote_modal.is_some() {
+            Mode::Create
+        } else if self.bookmarks_modal.is_some()
+            || self.new_bookmark_name.is_some()
+            || self.focused_pane().filter_query.is_some()
+        {
+            Mode::Command
+        } else {
+            Mode::Normal
+        }
+    }
+
+    /// Immutable reference to the given pane.
+    pub fn pane(&self, id: Panel) -> &Pane {
+        match id {
+            Panel::Left => &self.left,
+            Panel::Right => &self.right,
+        }
+    }
+
+    /// Mutable reference to the given pane.
+    pub fn pane_mut(&mut self, id: Panel) -> &mut Pane {
+        match id {
+            Panel::Left => &mut self.left,
+            Panel::Right => &mut self.right,
+        }
+    }
+
+    /// The currently focused pane.
+    pub fn focused_pane(&self) -> &Pane {
+        self.pane(self.focused_panel)
+    }
+
+    /// The currently focused pane, mutably.
+    pub fn focused_pane_mut(&mut self) -> &mut Pane {
+        self.pane_mut(self.focused_panel)
+    }
+
+    /// The panel that isn't currently focused.
+    fn other_panel(&self) -> Panel {
+        match self.focused_panel {
+            Panel::Left => Panel::Right,
+            Panel::Right => Panel::Left,
+        }
+    }
+
+    /// Indices into the shared `remotes` list that match `panel`'s filter
+    /// query, ranked by descending fuzzy match score. Identity mapping when
+    /// no filter is active.
+    pub fn filtered_remote_indices(&self, panel: Panel) -> Vec<usize> {
+        match &self.pane(panel).filter_query {
+            Some(query) if !query.is_empty() => {
+                let mut scored: Vec<(usize, i32)> = self
+                    .remotes
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, name)| {
+                        crate::app::filter::fuzzy_match(query, name).map(|m| (i, m.score))
+                    })
+                    .collect();
+                scored.sort_by(|a, b| b.1.cmp(&a.1));
+                scored.into_iter().map(|(i, _)| i).collect()
+            }
+            _ => (0..self.remotes.len()).collect(),
+        }
+    }
+
+    /// The remote currently highlighted in `panel`, accounting for its
+    /// active filter.
+    pub fn selected_remote(&self, panel: Panel) -> Option<&String> {
+        let indices = self.filtered_remote_indices(panel);
+        let idx = *indices.get(self.pane(panel).selected)?;
+        self.remotes.get(idx)
+    }
+
+    /// Queue a background delete for every file selected in the focused
+    /// pane, then reload its directory. Only `selected_files` is deleted;
+    /// with nothing selected, no delete is queued.
+    ///
+    /// # Errors
+    /// Returns error if rclone API calls fail.
+    pub async fn delete_selected_files(&mut self) -> Result<()> {
+        let panel = self.focused_panel;
+        let pane = self.pane(panel);
+        let Some(remote) = pane.remote.clone() else {
+            return Ok(());
+        };
+        let path = pane.path.clone();
+        let fs_path = format!("{}:", remote);
+
+        let targets: Vec<(String, bool)> = pane
+            .selected_files
+            .iter()
+            .filter_map(|&i| {
+                pane.files
+                    .get(i)
+                    .map(|item| (item.name().to_string(), item.is_dir()))
+            })
+            .collect();
+
+        // Group every delete spawned by this call together, so their
+        // progress doesn't get mixed up with an unrelated concurrent job
+        // that happens to touch a same-named file elsewhere.
+        let group = format!("lazyfile-delete-{}:{}", remote, path);
+
+        for (name, is_dir) in targets {
+            let remote_path = if path.is_empty() {
+                name.clone()
+            } else {
+                format!("{}/{}", path.trim_start_matches('/'), name)
+            };
+            let method = if is_dir {
+                commands::PURGE
+            } else {
+                commands::DELETE_FILE
+            };
+
+            info!("Deleting {}", remote_path);
+            self.submit_job(
+                method,
+                serde_json::json!({ "fs": fs_path, "remote": remote_path }),
+                remote_path.clone(),
+                format!("Delete {}", name),
+                Some(&group),
+            )
+            .await?;
+        }
+
+        self.pane_mut(panel).selected_files.clear();
+        self.listing_cache.invalidate(&remote, &path);
+        self.load_files(panel).await
+    }
+
+    /// Copy the focused pane's selected files (or the highlighted item, if
+    /// none are selected) into the other pane's current directory.
+    ///
+    /// # Errors
+    /// Returns error if rclone API calls fail.
+    pub async fn copy_selection_to_other_pane(&mut self) -> Result<()> {
+        self.transfer_selection_to_other_pane(false).await
+    }
+
+    /// Move the focused pane's selected files (or the highlighted item, if
+    /// none are selected) into the other pane's current directory.
+    ///
+    /// # Errors
+    /// Returns error if rclone API calls fail.
+    pub async fn move_selection_to_other_pane(&mut self) -> Result<()> {
+        self.transfer_selection_to_other_pane(true).await
+    }
+
+    /// Shared implementation for copying/moving the focused pane's
+    /// selection across into the other pane, bailing out if either pane
+    /// isn't currently open on a remote.
+    async fn transfer_selection_to_other_pane(&mut self, is_move: bool) -> Result<()> {
+        let src_panel = self.focused_panel;
+        let dst_panel = self.other_panel();
+
+        let src = self.pane(src_panel);
+        let Some(src_remote) = src.remote.clone() else {
+            return Ok(());
+        };
+        let src_path = src.path.clone();
+        let mut indices: Vec<usize> = if src.selected_files.is_empty() {
+            src.selected_file_index().into_iter().collect()
+        } else {
+            src.selected_files.iter().copied().collect()
+        };
+        indices.sort_unstable();
+        let names: Vec<(String, bool)> = indices
+            .into_iter()
+            .filter_map(|i| {
+                src.files
+                    .get(i)
+                    .map(|item| (item.name().to_string(), item.is_dir()))
+            })
+            .collect();
+
+        let dst = self.pane(dst_panel);
+        let Some(dst_remote) = dst.remote.clone() else {
+            return Ok(());
+        };
+        let dst_path = dst.path.clone();
+
+        let src_fs = format!("{}:", src_remote);
+        let dst_fs = format!("{}:", dst_remote);
+
+        // Group every transfer spawned by this call together, so their
+        // progress doesn't get mixed up with an unrelated concurrent job
+        // that happens to touch a same-named file elsewhere.
+        let group = format!(
+            "lazyfile-transfer-{}:{}->{}:{}",
+            src_remote, src_path, dst_remote, dst_path
+        );
+
+        for (name, is_dir) in &names {
+            let src_remote_path = if src_path.is_empty() {
+                name.clone()
+            } else {
+                format!("{}/{}", src_path.trim_start_matches('/'), name)
+            };
+            let dst_remote_path = if dst_path.is_empty() {
+                name.clone()
+            } else {
+                format!("{}/{}", dst_path.trim_start_matches('/'), name)
+            };
+
+            let verb = if is_move { "Moving" } else { "Copying" };
+            let label = if is_move { "Move" } else { "Copy" };
+
+            if *is_dir {
+                let src_dir_fs = format!("{}{}", src_fs, src_remote_path);
+                let dst_dir_fs = format!("{}{}", dst_fs, dst_remote_path);
+                let method = if is_move {
+                    commands::SYNC_MOVE
+                } else {
+                    commands::SYNC_COPY
+                };
+
+                info!("{} directory {} -> {}", verb, src_dir_fs, dst_dir_fs);
+                self.submit_job(
+                    method,
+                    serde_json::json!({ "srcFs": src_dir_fs, "dstFs": dst_dir_fs }),
+                    src_dir_fs,
+                    format!("{} {}", label, name),
+                    Some(&group),
+                )
+                .await?;
+            } else {
+                let method = if is_move {
+                    commands::MOVE_FILE
+                } else {
+                    commands::COPY_FILE
+                };
+
+                info!(
+                    "{} {}{} -> {}{}",
+                    verb, src_fs, src_remote_path, dst_fs, dst_remote_path
+                );
+                self.submit_job(
+                    method,
+                    serde_json::json!({
+                        "srcFs": src_fs,
+                        "srcRemote": src_remote_path,
+                        "dstFs": dst_fs,
+                        "dstRemote": dst_remote_path,
+                    }),
+                    src_remote_path.clone(),
+                    format!("{} {}", label, name),
+                    Some(&group),
+                )
+                .await?;
+            }
+        }
+
+        self.pane_mut(src_panel).selected_files.clear();
+        self.listing_cache.invalidate(&src_remote, &src_path);
+        self.listing_cache.invalidate(&dst_remote, &dst_path);
+        self.load_files(src_panel).await?;
+        self.load_files(dst_panel).await
+    }
+
+    /// Save a bookmark for the focused pane's current location under `name`.
+    pub fn add_bookmark(&mut self, name: impl Into<String>) {
+        let pane = self.focused_pane();
+        let Some(ref remote) = pane.remote else {
+            return;
+        };
+        let remote = remote.clone();
+        let path = pane.path.clone();
+        self.bookmarks.add(name, &remote, &path);
+        if let Err(e) = self.bookmarks.save() {
+            warn!("Failed to save bookmarks: {}", e);
+        }
+    }
+
+    /// Jump the focused pane to a bookmarked `remote:path` location and
+    /// reload its file list.
+    ///
+    /// # Errors
+    /// Returns error if rclone API calls fail.
+    pub async fn jump_to_bookmark(&mut self, location: &str) -> Result<()> {
+        let (remote, path) = Bookmarks::split_location(location);
+        info!("Jumping to bookmark {}", location);
+        let panel = self.focused_panel;
+        let pane = self.pane_mut(panel);
+        pane.remote = Some(remote);
+        pane.path = path;
+        self.load_files(panel).await
+    }
+
+    /// Submit an rclone operation to run in the background.
+    ///
+    /// If the daemon returns a jobid the job is tracked in `jobs` and its
+    /// progress is picked up on the next `poll_jobs` tick. If `_async` isn't
+    /// supported the daemon runs the request synchronously and nothing is
+    /// tracked.
+    ///
+    /// `group` scopes stats for multi-file operations (e.g. deleting several
+    /// selected files) together, so their progress isn't mixed up with an
+    /// unrelated job that happens to touch a same-named file. Pass `None`
+    /// for one-off jobs.
+    ///
+    /// `name` is the remote-relative path rclone reports in
+    /// `core/stats`' `transferring[].name`, used by `poll_jobs` to match
+    /// this job against its live throughput. `description` is the
+    /// human-readable label shown in the transfers panel.
+    ///
+    /// # Errors
+    /// Returns error if rclone API calls fail.
+    pub async fn submit_job(
+        &mut self,
+        method: &str,
+        body: serde_json::Value,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        group: Option<&str>,
+    ) -> Result<()> {
+        if let Some(jobid) = self.client.submit_async(method, body, group).await? {
+            debug!("Tracking job {} for {}", jobid, method);
+            self.jobs.push(Job::new(
+                jobid,
+                name,
+                description,
+                group.map(String::from),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Poll in-flight background jobs, dropping any that have finished and
+    /// surfacing the last error (if any) via `job_error`.
+    ///
+    /// Daemon failures while polling are logged rather than returned; see
+    /// `jobs::poll_jobs`.
+    pub async fn poll_jobs(&mut self) -> Result<()> {
+        if let Some(error) = crate::app::jobs::poll_jobs(&self.client, &mut self.jobs).await? {
+            self.job_error = Some(error);
+        }
+        Ok(())
+    }
+
+    /// Cancel an in-flight background job by id.
+    ///
+    /// # Errors
+    /// Returns error if rclone API calls fail.
+    pub async fn stop_job(&mut self, id: u64) -> Result<()> {
+        crate::app::jobs::stop_job(&self.client, &mut self.jobs, id).await
+    }
+
     /// Load remotes from rclone daemon.
     pub async fn load_remotes(&mut self) -> Result<()> {
         debug!("Loading remotes");
         self.remotes = self.client.list_remotes().await?;
-        self.remotes_selected = 0;
+        if self.left.remote.is_none() {
+            self.left.selected = 0;
+        }
+        if self.right.remote.is_none() {
+            self.right.selected = 0;
+        }
         info!("Loaded {} remotes", self.remotes.len());
         Ok(())
     }
 
-    /// Load files from current remote and path.
-    pub async fn load_files(&mut self) -> Result<()> {
-        if let Some(ref remote) = self.current_remote {
-            debug!("Loading files from {}:{}", remote, self.current_path);
-            let items = self.client.list_files(remote, &self.current_path).await?;
-            self.files = items.into_iter().map(NavigationItem::File).collect();
-            info!("Loaded {} files", self.files.len());
+    /// Load files into `panel` from its current remote and path.
+    ///
+    /// Serves from the listing cache when a fresh entry exists for the
+    /// `(remote, path)`, so repeated visits (e.g. Backspace back to a
+    /// parent directory) render instantly. On a cache miss the listing
+    /// runs on a background task and is picked up by `poll_pending_loads`,
+    /// so this never blocks the key handler.
+    pub async fn load_files(&mut self, panel: Panel) -> Result<()> {
+        self.pane_mut(panel).selected_files.clear();
+
+        let Some(remote) = self.pane(panel).remote.clone() else {
+            return Ok(());
+        };
+        let path = self.pane(panel).path.clone();
+
+        if let Some(cached) = self.listing_cache.get(&remote, &path) {
+            debug!("Rendering {}:{} from cache", remote, path);
+            let files: Vec<NavigationItem> =
+                cached.iter().cloned().map(NavigationItem::File).collect();
+            let pane = self.pane_mut(panel);
+            pane.files = files;
+            pane.selected = 0;
+            return Ok(());
         }
-        self.files_selected = 0;
+
+        debug!("Loading files from {}:{} in the background", remote, path);
+        self.pending_loads.retain(|load| load.panel != panel);
+        self.pending_loads.push(PendingLoad::spawn(
+            panel,
+            self.client.clone(),
+            remote,
+            path,
+        ));
+        self.loading = true;
         Ok(())
     }
 
-    /// Move selection down in focused panel.
-    pub fn navigate_down(&mut self) {
-        match self.focused_panel {
-            Panel::Remotes => {
-                if self.remotes_selected < self.remotes.len().saturating_sub(1) {
-                    self.remotes_selected += 1;
-                    debug!("Navigate down in remotes: {}", self.remotes_selected);
-                }
-            }
-            Panel::Files => {
-                if self.files_selected < self.files.len().saturating_sub(1) {
-                    self.files_selected += 1;
-                    debug!("Navigate down in files: {}", self.files_selected);
+    /// Apply results from any background directory listings started by
+    /// `load_files` that have finished since the last tick.
+    ///
+    /// A failed listing doesn't propagate or stop the app; it's surfaced via
+    /// `job_error` (same as a failed background job) and the remaining
+    /// finished loads are still applied.
+    pub async fn poll_pending_loads(&mut self) -> Result<()> {
+        for (panel, remote, path, result) in loader::drain_finished(&mut self.pending_loads).await
+        {
+            let items = match result {
+                Ok(items) => items,
+                Err(e) => {
+                    warn!("Failed to load {}:{}: {}", remote, path, e);
+                    self.job_error = Some(e.to_string());
+                    continue;
                 }
+            };
+            self.listing_cache.put(&remote, &path, items.clone());
+
+            let pane = self.pane_mut(panel);
+            if pane.remote.as_deref() == Some(remote.as_str()) && pane.path == path {
+                pane.files = items.into_iter().map(NavigationItem::File).collect();
+                pane.selected = 0;
+                info!("Loaded {} files into {:?}", pane.files.len(), panel);
             }
         }
+        self.loading = !self.pending_loads.is_empty();
+        Ok(())
     }
 
-    /// Move selection up in focused panel.
-    pub fn navigate_up(&mut self) {
-        match self.focused_panel {
-            Panel::Remotes => {
-                if self.remotes_selected > 0 {
-                    self.remotes_selected -= 1;
-                    debug!("Navigate up in remotes: {}", self.remotes_selected);
-                }
-            }
-            Panel::Files => {
-                if self.files_selected > 0 {
-                    self.files_selected -= 1;
-                    debug!("Navigate up in files: {}", self.files_selected);
-                }
+    /// Silently refresh both panes' directory listings once
+    /// `AUTO_REFRESH_INTERVAL` has elapsed, so changes made outside LazyFile
+    /// show up without a manual Refresh. Polled each iteration of the main
+    /// loop; a no-op when the interval hasn't elapsed yet.
+    ///
+    /// A failed refresh doesn't propagate or stop the app; it's surfaced via
+    /// `job_error` and the other pane still gets its chance to refresh.
+    pub async fn auto_refresh_tick(&mut self) -> Result<()> {
+        if self.last_auto_refresh.elapsed() < AUTO_REFRESH_INTERVAL {
+            return Ok(());
+        }
+        self.last_auto_refresh = Instant::now();
+
+        for panel in [Panel::Left, Panel::Right] {
+            let Some(remote) = self.pane(panel).remote.clone() else {
+                continue;
+            };
+            let path = self.pane(panel).path.clone();
+            debug!("Auto-refreshing {}:{}", remote, path);
+            self.listing_cache.invalidate(&remote, &path);
+            if let Err(e) = self.load_files(panel).await {
+                warn!("Auto-refresh failed for {}:{}: {}", remote, path, e);
+                self.job_error = Some(e.to_string());
             }
         }
+        Ok(())
     }
 
-    /// Switch focus between remotes and files panels.
-    pub fn switch_panel(&mut self) {
-        self.focused_panel = match self.focused_panel {
-            Panel::Remotes => {
-                debug!("Switching focus to Files");
-                Panel::Files
-            }
-            Panel::Files => {
-                debug!("Switching focus to Remotes");
-                Panel::Remotes
+    /// Bypass and invalidate the listing cache for the focused pane's
+    /// current path, then reload its file list from the rclone daemon.
+    ///
+    /// # Errors
+    /// Returns error if rclone API calls fail.
+    pub async fn refresh_files(&mut self) -> Result<()> {
+        let panel = self.focused_panel;
+        if let Some(remote) = self.pane(panel).remote.clone() {
+            let path = self.pane(panel).path.clone();
+            debug!("Manually refreshing {}:{}", remote, path);
+            self.listing_cache.invalidate(&remote, &path);
+        }
+        self.load_files(panel).await
+    }
+
+    /// Refresh the preview pane for the focused pane's currently selected
+    /// file.
+    ///
+    /// Debounced against `(remote:path, selected file index)` so repeated
+    /// calls between selection changes are a no-op.
+    ///
+    /// # Errors
+    /// Returns error if rclone API calls fail.
+    pub async fn update_preview(&mut self) -> Result<()> {
+        if !self.preview_visible {
+            return Ok(());
+        }
+
+        let pane = self.focused_pane();
+        let Some(remote) = pane.remote.clone() else {
+            self.preview = PreviewContent::Empty;
+            self.preview_key = None;
+            return Ok(());
+        };
+        let path = pane.path.clone();
+        let Some(selected) = pane.selected_file_index() else {
+            self.preview = PreviewContent::Empty;
+            self.preview_key = None;
+            return Ok(());
+        };
+
+        let key = (format!("{}:{}", remote, path), selected);
+        if self.preview_key.as_ref() == Some(&key) {
+            return Ok(());
+        }
+        self.preview_key = Some(key);
+
+        let Some(NavigationItem::File(item)) =
+            self.focused_pane().files.get(selected).cloned()
+        else {
+            self.preview = PreviewContent::Empty;
+            return Ok(());
+        };
+
+        if item.is_dir {
+            self.preview = PreviewContent::Empty;
+            return Ok(());
+        }
+
+        let file_path = if path.is_empty() {
+            format!("/{}", item.name)
+        } else {
+            format!("{}/{}", path, item.name)
+        };
+
+        debug!("Fetching preview for {}", file_path);
+        let bytes = self
+            .client
+            .cat_file(&remote, &file_path, PREVIEW_BYTE_LIMIT)
+            .await?;
+
+        self.preview = match String::from_utf8(bytes) {
+            Ok(body) if !body.contains('\0') => {
+                let extension = std::path::Path::new(&item.name)
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or("")
+                    .to_string();
+                PreviewContent::Text { extension, body }
             }
+            _ => PreviewContent::Binary { size: item.size },
+        };
+        Ok(())
+    }
+
+    /// Move the focused pane's selection down, within the filtered subset.
+    pub fn navigate_down(&mut self) {
+        let panel = self.focused_panel;
+        let len = if self.pane(panel).remote.is_none() {
+            self.filtered_remote_indices(panel).len()
+        } else {
+            self.pane(panel).filtered_file_indices().len()
         };
+        self.pane_mut(panel).navigate_down(len);
+    }
+
+    /// Move the focused pane's selection up.
+    pub fn navigate_up(&mut self) {
+        self.focused_pane_mut().navigate_up();
+    }
+
+    /// Switch focus between the left and right panes. Each pane keeps its
+    /// own selection, so switching focus to line up a transfer destination
+    /// doesn't lose the other pane's batch selection.
+    pub fn switch_panel(&mut self) {
+        self.focused_panel = self.other_panel();
+        debug!("Switched focus to {:?}", self.focused_panel);
     }
 }
 
@@ -149,16 +697,22 @@ mod tests {
         let app = App::new(client);
 
         assert!(app.remotes.is_empty());
-        assert!(app.current_remote.is_none());
-        assert_eq!(app.current_path, "");
-        assert!(app.files.is_empty());
-        assert_eq!(app.remotes_selected, 0);
-        assert_eq!(app.files_selected, 0);
-        assert_eq!(app.focused_panel, Panel::Remotes);
+        assert!(app.left.remote.is_none());
+        assert!(app.right.remote.is_none());
+        assert_eq!(app.focused_panel, Panel::Left);
         assert!(app.running);
         assert!(app.create_remote_modal.is_none());
         assert!(app.confirm_modal.is_none());
         assert!(app.pending_delete_remote.is_none());
+        assert!(app.jobs.is_empty());
+        assert!(app.job_error.is_none());
+        assert!(matches!(app.preview, PreviewContent::Empty));
+        assert!(app.preview_visible);
+        assert!(app.bookmarks.is_empty());
+        assert!(app.bookmarks_modal.is_none());
+        assert!(app.new_bookmark_name.is_none());
+        assert!(!app.pending_delete_files);
+        assert!(!app.loading);
     }
 
     #[test]
@@ -166,20 +720,21 @@ mod tests {
         let client = create_test_client();
         let mut app = App::new(client);
         app.remotes = vec!["remote1".to_string(), "remote2".to_string()];
-        app.focused_panel = Panel::Remotes;
+        app.focused_panel = Panel::Left;
 
         app.navigate_down();
-        assert_eq!(app.remotes_selected, 1);
+        assert_eq!(app.left.selected, 1);
 
         app.navigate_down();
-        assert_eq!(app.remotes_selected, 1); // stays at max
+        assert_eq!(app.left.selected, 1); // stays at max
     }
 
     #[test]
     fn test_navigate_down_files() {
         let client = create_test_client();
         let mut app = App::new(client);
-        app.files = vec![
+        app.left.remote = Some("remote1".to_string());
+        app.left.files = vec![
             NavigationItem::File(FileItem {
                 name: "file1".to_string(),
                 size: 100,
@@ -193,10 +748,10 @@ mod tests {
                 is_dir: false,
             }),
         ];
-        app.focused_panel = Panel::Files;
+        app.focused_panel = Panel::Left;
 
         app.navigate_down();
-        assert_eq!(app.files_selected, 1);
+        assert_eq!(app.left.selected, 1);
     }
 
     #[test]
@@ -204,51 +759,64 @@ mod tests {
         let client = create_test_client();
         let mut app = App::new(client);
         app.remotes = vec!["remote1".to_string(), "remote2".to_string()];
-        app.remotes_selected = 1;
-        app.focused_panel = Panel::Remotes;
+        app.left.selected = 1;
+        app.focused_panel = Panel::Left;
 
         app.navigate_up();
-        assert_eq!(app.remotes_selected, 0);
+        assert_eq!(app.left.selected, 0);
 
         app.navigate_up();
-        assert_eq!(app.remotes_selected, 0); // stays at min
+        assert_eq!(app.left.selected, 0); // stays at min
     }
 
     #[test]
     fn test_navigate_up_files() {
         let client = create_test_client();
         let mut app = App::new(client);
-        app.files = vec![NavigationItem::File(FileItem {
+        app.right.remote = Some("remote1".to_string());
+        app.right.files = vec![NavigationItem::File(FileItem {
             name: "file1".to_string(),
             size: 100,
             mod_time: "".to_string(),
             is_dir: false,
         })];
-        app.files_selected = 1;
-        app.focused_panel = Panel::Files;
+        app.right.selected = 1;
+        app.focused_panel = Panel::Right;
 
         app.navigate_up();
-        assert_eq!(app.files_selected, 0);
+        assert_eq!(app.right.selected, 0);
+    }
+
+    #[test]
+    fn test_switch_panel_to_right() {
+        let client = create_test_client();
+        let mut app = App::new(client);
+        assert_eq!(app.focused_panel, Panel::Left);
+
+        app.switch_panel();
+        assert_eq!(app.focused_panel, Panel::Right);
     }
 
     #[test]
-    fn test_switch_panel_to_files() {
+    fn test_switch_panel_to_left() {
         let client = create_test_client();
         let mut app = App::new(client);
-        assert_eq!(app.focused_panel, Panel::Remotes);
+        app.focused_panel = Panel::Right;
 
         app.switch_panel();
-        assert_eq!(app.focused_panel, Panel::Files);
+        assert_eq!(app.focused_panel, Panel::Left);
     }
 
     #[test]
-    fn test_switch_panel_to_remotes() {
+    fn test_switch_panel_preserves_other_panes_selection() {
         let client = create_test_client();
         let mut app = App::new(client);
-        app.focused_panel = Panel::Files;
+        app.left.toggle_file_selection(0);
 
         app.switch_panel();
-        assert_eq!(app.focused_panel, Panel::Remotes);
+        app.switch_panel();
+
+        assert!(app.left.selected_files.contains(&0));
     }
 
     #[test]
@@ -257,9 +825,9 @@ mod tests {
         let mut app = App::new(client);
 
         for _ in 0..4 {
-            assert_eq!(app.focused_panel, Panel::Remotes);
+            assert_eq!(app.focused_panel, Panel::Left);
             app.switch_panel();
-            assert_eq!(app.focused_panel, Panel::Files);
+            assert_eq!(app.focused_panel, Panel::Right);
             app.switch_panel();
         }
     }
@@ -0,0 +1,228 @@
+//! Configurable key bindings.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// An input mode, the way xplr organizes key behavior. Which mode is active
+/// is derived from `App`'s current state (which modal, if any, is open).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Browsing panes, no modal or prompt open.
+    Normal,
+    /// The create/edit remote modal is open.
+    Create,
+    /// A yes/no confirmation modal is open.
+    Confirm,
+    /// A free-form text prompt is open (filter query, bookmark name, bookmarks popup).
+    Command,
+}
+
+/// A named action a key can be bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    /// Quit the application.
+    Quit,
+    /// Start an incremental filter on the focused pane.
+    StartFilter,
+    /// Open the create remote modal.
+    AddRemote,
+    /// Delete the selected remote, or the focused pane's selected files.
+    Delete,
+    /// Open the edit remote modal for the selected remote.
+    EditRemote,
+    /// Toggle selection of the highlighted file.
+    ToggleSelection,
+    /// Copy the focused pane's selection into the other pane.
+    CopySelection,
+    /// Move the focused pane's selection into the other pane.
+    MoveSelection,
+    /// Select every visible file in the focused pane.
+    SelectAll,
+    /// Prompt for a name to bookmark the focused pane's current location.
+    BookmarkPrompt,
+    /// Open the bookmarks popup.
+    OpenBookmarks,
+    /// Toggle the preview pane.
+    TogglePreview,
+    /// Bypass the listing cache and reload the focused pane.
+    Refresh,
+    /// Cycle the focused pane's files sort field.
+    CycleSort,
+    /// Reverse the focused pane's files sort direction.
+    ToggleSortDirection,
+    /// Cancel the oldest in-flight background job.
+    CancelJob,
+    /// Move the focused pane's selection down.
+    NavigateDown,
+    /// Move the focused pane's selection up.
+    NavigateUp,
+    /// Switch focus between the left and right panes.
+    SwitchPane,
+    /// Open the highlighted remote or directory (a no-op on a plain file).
+    Open,
+    /// Go back to the parent directory or the remotes list.
+    Back,
+    /// Accept the open confirmation modal.
+    Confirm,
+    /// Dismiss the open confirmation modal.
+    Cancel,
+    /// Toggle which option the open confirmation modal has selected.
+    Toggle,
+}
+
+/// Per-mode key bindings. User bindings loaded from `Config` take precedence
+/// over the built-in defaults for a given `(mode, key)` pair; any key left
+/// unbound falls through to the defaults.
+///
+/// Only `Normal` and `Confirm` are driven through this table — `Create` and
+/// `Command` are handled directly by `Handler` (typed characters plus a few
+/// fixed keys such as Esc, Enter and Tab or the arrows), so `resolve` returns
+/// no action for them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Keymap {
+    /// Overrides for `Mode::Normal`.
+    #[serde(default)]
+    normal: HashMap<String, Action>,
+    /// Overrides for `Mode::Confirm`.
+    #[serde(default)]
+    confirm: HashMap<String, Action>,
+}
+
+impl Keymap {
+    /// Resolve a pressed key (see `key_string`) to an action for `mode`,
+    /// preferring a user override over the built-in default.
+    pub fn resolve(&self, mode: Mode, key: &str) -> Option<Action> {
+        match mode {
+            Mode::Normal => self
+                .normal
+                .get(key)
+                .copied()
+                .or_else(|| default_normal_action(key)),
+            Mode::Confirm => self
+                .confirm
+                .get(key)
+                .copied()
+                .or_else(|| default_confirm_action(key)),
+            Mode::Create | Mode::Command => None,
+        }
+    }
+}
+
+/// The built-in `Mode::Normal` bindings.
+fn default_normal_action(key: &str) -> Option<Action> {
+    use Action::*;
+    Some(match key {
+        "q" => Quit,
+        "/" => StartFilter,
+        "a" => AddRemote,
+        "d" => Delete,
+        "e" => EditRemote,
+        "m" => ToggleSelection,
+        "c" => CopySelection,
+        "M" => MoveSelection,
+        "ctrl+a" => SelectAll,
+        "B" => BookmarkPrompt,
+        "'" => OpenBookmarks,
+        "p" => TogglePreview,
+        "R" => Refresh,
+        "s" => CycleSort,
+        "S" => ToggleSortDirection,
+        "x" => CancelJob,
+        "j" | "down" => NavigateDown,
+        "k" | "up" => NavigateUp,
+        "tab" => SwitchPane,
+        "enter" => Open,
+        "backspace" => Back,
+        _ => return None,
+    })
+}
+
+/// The built-in `Mode::Confirm` bindings.
+fn default_confirm_action(key: &str) -> Option<Action> {
+    use Action::*;
+    Some(match key {
+        "esc" => Cancel,
+        "tab" | "left" | "right" => Toggle,
+        "enter" => Confirm,
+        _ => return None,
+    })
+}
+
+/// Render a key event as the canonical string used to look it up in a
+/// `Keymap`, e.g. `"j"`, `"M"`, `"ctrl+a"`, `"enter"`.
+pub fn key_string(key: &KeyEvent) -> String {
+    if let KeyCode::Char(c) = key.code {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return format!("ctrl+{}", c.to_ascii_lowercase());
+        }
+        return c.to_string();
+    }
+
+    match key.code {
+        KeyCode::Enter => "enter",
+        KeyCode::Esc => "esc",
+        KeyCode::Backspace => "backspace",
+        KeyCode::Tab => "tab",
+        KeyCode::BackTab => "backtab",
+        KeyCode::Up => "up",
+        KeyCode::Down => "down",
+        KeyCode::Left => "left",
+        KeyCode::Right => "right",
+        _ => "",
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_key_string_char() {
+        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        assert_eq!(key_string(&key), "j");
+    }
+
+    #[test]
+    fn test_key_string_shifted_char() {
+        let key = KeyEvent::new(KeyCode::Char('M'), KeyModifiers::NONE);
+        assert_eq!(key_string(&key), "M");
+    }
+
+    #[test]
+    fn test_key_string_ctrl() {
+        let key = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL);
+        assert_eq!(key_string(&key), "ctrl+a");
+    }
+
+    #[test]
+    fn test_key_string_named() {
+        let key = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(key_string(&key), "enter");
+    }
+
+    #[test]
+    fn test_resolve_default_normal() {
+        let keymap = Keymap::default();
+        assert_eq!(keymap.resolve(Mode::Normal, "j"), Some(Action::NavigateDown));
+        assert_eq!(keymap.resolve(Mode::Normal, "q"), Some(Action::Quit));
+        assert_eq!(keymap.resolve(Mode::Normal, "z"), None);
+    }
+
+    #[test]
+    fn test_resolve_user_override_takes_precedence() {
+        let mut keymap = Keymap::default();
+        keymap.normal.insert("j".to_string(), Action::Quit);
+        assert_eq!(keymap.resolve(Mode::Normal, "j"), Some(Action::Quit));
+    }
+
+    #[test]
+    fn test_resolve_create_and_command_are_unbound() {
+        let keymap = Keymap::default();
+        assert_eq!(keymap.resolve(Mode::Create, "j"), None);
+        assert_eq!(keymap.resolve(Mode::Command, "j"), None);
+    }
+}
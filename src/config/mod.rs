@@ -1,6 +1,118 @@
-//! Configuration constants.
+//! Configuration constants and user-configurable key bindings.
+
+pub mod keymap;
+
+pub use keymap::{Action, Keymap, Mode};
+
+use crate::error::{LazyFileError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::warn;
 
 /// Default rclone daemon host.
 pub const RCLONE_HOST: &str = "localhost";
 /// Default rclone daemon port.
 pub const RCLONE_PORT: u16 = 5572;
+/// How often an open pane's directory listing is silently refreshed in the
+/// background, so changes made outside LazyFile show up without a manual
+/// Refresh.
+pub const AUTO_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Name of the config file within the XDG config dir.
+const CONFIG_FILE: &str = "config.toml";
+
+/// User-facing configuration, loaded from a TOML file at startup.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    /// Key bindings, overriding the built-in defaults per mode.
+    #[serde(default)]
+    pub keymap: Keymap,
+    /// How to connect to the rclone rc daemon.
+    #[serde(default)]
+    pub rclone: RcloneConfig,
+}
+
+/// Controls how LazyFile connects to the rclone rc daemon.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RcloneConfig {
+    /// Daemon host, overriding `RCLONE_HOST`.
+    pub host: Option<String>,
+    /// Daemon port, overriding `RCLONE_PORT`.
+    pub port: Option<u16>,
+    /// When set, LazyFile spawns and supervises its own `rclone rcd`
+    /// process (secured with these basic-auth credentials) instead of
+    /// connecting to one the user already has running.
+    pub managed: Option<ManagedRcloneConfig>,
+}
+
+/// Basic-auth credentials for a LazyFile-managed `rclone rcd` process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManagedRcloneConfig {
+    /// `--rc-user` value passed to the spawned daemon.
+    pub rc_user: String,
+    /// `--rc-pass` value passed to the spawned daemon.
+    pub rc_pass: String,
+}
+
+impl Config {
+    /// Load config from the XDG config dir, falling back to defaults if the
+    /// file doesn't exist or fails to parse.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents).unwrap_or_else(|e| {
+                warn!("Failed to parse config file: {}", e);
+                Self::default()
+            }),
+            Err(e) => {
+                warn!("Failed to read config file: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Parse config from a TOML string.
+    ///
+    /// # Errors
+    /// Returns error if `contents` isn't valid TOML matching the config schema.
+    pub fn parse(contents: &str) -> Result<Self> {
+        toml::from_str(contents)
+            .map_err(|e| LazyFileError::Config(format!("invalid config: {}", e)))
+    }
+
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("lazyfile").join(CONFIG_FILE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty_config() {
+        let config = Config::parse("").unwrap();
+        assert!(config.keymap.resolve(Mode::Normal, "q").is_some());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_toml() {
+        assert!(Config::parse("not valid toml = = =").is_err());
+    }
+
+    #[test]
+    fn test_parse_keymap_override() {
+        let config = Config::parse("[keymap.normal]\nx = \"quit\"\n").unwrap();
+        assert_eq!(
+            config.keymap.resolve(Mode::Normal, "x"),
+            Some(Action::Quit)
+        );
+    }
+}
@@ -13,6 +13,11 @@ pub enum LazyFileError {
     #[error("Rclone API error: {0}")]
     RcloneApi(String),
 
+    /// A request to the rclone daemon exhausted its retry budget, e.g. the
+    /// daemon stayed unreachable or kept returning 429/503.
+    #[error("Rclone daemon unreachable: {0}")]
+    RetriesExhausted(String),
+
     /// HTTP request error.
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
@@ -26,9 +31,8 @@ pub enum LazyFileError {
     #[allow(dead_code)]
     Terminal(String),
 
-    /// Configuration error (reserved for future use).
+    /// Configuration error.
     #[error("Configuration error: {0}")]
-    #[allow(dead_code)]
     Config(String),
 
     /// Tracing filter parse error.
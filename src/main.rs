@@ -6,8 +6,9 @@ mod error;
 mod rclone;
 mod ui;
 
+use app::state::Panel;
 use app::{App, Handler};
-use config::{RCLONE_HOST, RCLONE_PORT};
+use config::{Config, RCLONE_HOST, RCLONE_PORT};
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event},
     execute,
@@ -27,7 +28,7 @@ async fn main() -> error::Result<()> {
 
     tracing::debug!("Starting LazyFile");
 
-    let client = RcloneClient::new(RCLONE_HOST, RCLONE_PORT);
+    let client = connect_rclone(&Config::load()).await?;
     let mut app = App::new(client);
     app.load_remotes().await?;
 
@@ -35,9 +36,28 @@ async fn main() -> error::Result<()> {
     let res = run_app(&mut app).await;
     restore_terminal()?;
 
+    app.client.shutdown_managed_daemon().await;
     res
 }
 
+/// Build the `RcloneClient` for this run: a managed, basic-auth-secured
+/// `rclone rcd` this process spawns and supervises when `[rclone.managed]`
+/// is set in the config file, otherwise a client pointed at an
+/// already-running daemon on `[rclone].host`/`port` (defaulting to
+/// `RCLONE_HOST`/`RCLONE_PORT`).
+async fn connect_rclone(config: &Config) -> error::Result<RcloneClient> {
+    let host = config.rclone.host.as_deref().unwrap_or(RCLONE_HOST);
+    let port = config.rclone.port.unwrap_or(RCLONE_PORT);
+
+    match &config.rclone.managed {
+        Some(managed) => {
+            tracing::debug!("Spawning managed rclone rcd on {}:{}", host, port);
+            RcloneClient::spawn_managed(host, port, &managed.rc_user, &managed.rc_pass).await
+        }
+        None => Ok(RcloneClient::new(host, port)),
+    }
+}
+
 /// Initialize terminal for TUI.
 fn setup_terminal() -> error::Result<()> {
     enable_raw_mode()?;
@@ -66,6 +86,10 @@ async fn run_app(app: &mut App) -> error::Result<()> {
         {
             Handler::handle_key(app, key).await?;
         }
+
+        app.poll_jobs().await?;
+        app.poll_pending_loads().await?;
+        app.auto_refresh_tick().await?;
     }
 
     tracing::debug!("Application exiting");
@@ -74,34 +98,30 @@ async fn run_app(app: &mut App) -> error::Result<()> {
 
 /// Render the UI frame.
 fn ui_render(f: &mut Frame, app: &App) {
-    let rects = Layout::split(f.area());
+    let rects = Layout::split(f.area(), app.preview_visible);
 
     ui::HelpWidget::render(f, rects.help);
 
-    ui::RemoteListWidget::render(
-        f,
-        rects.remotes,
-        &app.remotes,
-        app.remotes_selected,
-        matches!(app.focused_panel, app::state::Panel::Remotes),
-    );
+    render_pane(f, rects.left, app, Panel::Left, "Left");
+    render_pane(f, rects.right, app, Panel::Right, "Right");
 
-    ui::FileListWidget::render(
-        f,
-        rects.files,
-        &app.files,
-        app.files_selected,
-        matches!(app.focused_panel, app::state::Panel::Files),
-    );
+    if app.preview_visible {
+        ui::PreviewWidget::render(f, rects.preview, &app.preview);
+    }
 
+    let focused = app.focused_pane();
     ui::StatusBarWidget::render(
         f,
         rects.status,
-        app.current_remote.as_deref(),
-        &app.current_path,
+        focused.remote.as_deref(),
+        &focused.path,
         true,
+        app.loading,
+        app.job_error.as_deref(),
     );
 
+    ui::JobsWidget::render(f, f.area(), &app.jobs);
+
     // Render confirmation modal if open
     if let Some(ref modal) = app.confirm_modal {
         ui::ConfirmWidget::render(f, f.area(), modal);
@@ -111,4 +131,46 @@ fn ui_render(f: &mut Frame, app: &App) {
     if let Some(ref modal) = app.create_remote_modal {
         ui::CreateRemoteWidget::render(f, f.area(), modal);
     }
+
+    // Render bookmarks popup if open
+    if let Some(ref modal) = app.bookmarks_modal {
+        ui::BookmarksWidget::render(f, f.area(), &app.bookmarks, modal);
+    }
+
+    // Render the new-bookmark name prompt if active
+    if let Some(ref name) = app.new_bookmark_name {
+        ui::BookmarksWidget::render_name_prompt(f, f.area(), name);
+    }
+}
+
+/// Render a single navigation pane: the remotes list while it has no
+/// remote open, otherwise its current directory's file list.
+fn render_pane(f: &mut Frame, area: Rect, app: &App, panel: Panel, label: &str) {
+    let pane = app.pane(panel);
+    let focused = app.focused_panel == panel;
+
+    if pane.remote.is_none() {
+        ui::RemoteListWidget::render(
+            f,
+            area,
+            label,
+            &app.remotes,
+            &app.filtered_remote_indices(panel),
+            pane.selected,
+            pane.filter_query.as_deref(),
+            focused,
+        );
+    } else {
+        ui::FileListWidget::render(
+            f,
+            area,
+            label,
+            &pane.files,
+            &pane.filtered_file_indices(),
+            pane.selected,
+            pane.filter_query.as_deref(),
+            focused,
+            &pane.selected_files,
+        );
+    }
 }
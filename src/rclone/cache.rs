@@ -0,0 +1,79 @@
+//! In-memory cache of directory listings, keyed by remote and path.
+
+use crate::rclone::types::FileItem;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Default time a cached listing is considered fresh.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(30);
+
+/// A single cached `operations/list` result.
+#[derive(Debug, Clone)]
+struct Entry {
+    items: Vec<FileItem>,
+    fetched_at: Instant,
+}
+
+/// Caches directory listings keyed by `(remote, path)` so repeated visits
+/// (e.g. Backspace back to a parent directory) can render instantly.
+///
+/// This is a plain time-based cache, not an HTTP conditional-GET layer:
+/// rclone's rc API is a set of POST endpoints with no `ETag`/`If-None-Match`
+/// semantics to revalidate against, so the only correctness knob available
+/// is a TTL short enough that a stale listing is never shown for long.
+#[derive(Debug)]
+pub struct ListingCache {
+    ttl: Duration,
+    entries: HashMap<(String, String), Entry>,
+}
+
+impl ListingCache {
+    /// Create a cache using `DEFAULT_TTL`.
+    pub fn new() -> Self {
+        Self::with_ttl(DEFAULT_TTL)
+    }
+
+    /// Create a cache with a custom TTL.
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Return the cached listing for `(remote, path)`, or `None` if there is
+    /// no entry or it is older than the configured TTL.
+    pub fn get(&self, remote: &str, path: &str) -> Option<&[FileItem]> {
+        let entry = self.entries.get(&Self::key(remote, path))?;
+        if entry.fetched_at.elapsed() > self.ttl {
+            return None;
+        }
+        Some(&entry.items)
+    }
+
+    /// Store a freshly fetched listing for `(remote, path)`.
+    pub fn put(&mut self, remote: &str, path: &str, items: Vec<FileItem>) {
+        self.entries.insert(
+            Self::key(remote, path),
+            Entry {
+                items,
+                fetched_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Drop the cached entry for `(remote, path)`, forcing a refetch next time.
+    pub fn invalidate(&mut self, remote: &str, path: &str) {
+        self.entries.remove(&Self::key(remote, path));
+    }
+
+    fn key(remote: &str, path: &str) -> (String, String) {
+        (remote.to_string(), path.to_string())
+    }
+}
+
+impl Default for ListingCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
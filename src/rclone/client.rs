@@ -1,32 +1,357 @@
 //! Rclone JSON-RPC client implementation.
 
 use crate::error::{LazyFileError, Result};
+use crate::rclone::commands;
 use crate::rclone::types::{
-    ConfigCreateRequest, ConfigDeleteRequest, ConfigUpdateRequest, FileItem,
+    ConfigCreateRequest, ConfigDeleteRequest, ConfigUpdateRequest, CoreCommandResponse,
+    CoreStatsResponse, FileItem, JobStatusResponse, Provider, ProvidersResponse,
 };
-use reqwest::Client;
+use reqwest::{Client, Response, StatusCode};
 use std::collections::HashMap;
-use tracing::{debug, error, trace};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::{debug, error, trace, warn};
+
+/// Default maximum number of retry attempts for a single request, on top of
+/// the initial attempt.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Default delay before the first retry, doubled on each subsequent attempt.
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(250);
+/// Upper bound on any single computed backoff delay.
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+
+/// A spawned `rclone rcd` child process. The intended shutdown path is
+/// `RcloneClient::shutdown_managed_daemon`, which asks the daemon to quit
+/// gracefully over its own rc API; `Drop` only kills the process outright as
+/// a last resort, if it's still running once the last clone of the owning
+/// `RcloneClient` is dropped (e.g. `shutdown_managed_daemon` was never
+/// called, or the daemon didn't respond to `core/quit`).
+struct ManagedDaemon(Mutex<Child>);
+
+impl ManagedDaemon {
+    /// True once the daemon has actually exited, without blocking for it.
+    fn has_exited(&self) -> bool {
+        matches!(self.0.lock().unwrap().try_wait(), Ok(Some(_)))
+    }
+}
+
+impl std::fmt::Debug for ManagedDaemon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ManagedDaemon").finish_non_exhaustive()
+    }
+}
+
+impl Drop for ManagedDaemon {
+    fn drop(&mut self) {
+        let mut child = self.0.lock().unwrap();
+        if matches!(child.try_wait(), Ok(Some(_))) {
+            return;
+        }
+        warn!("Managed rclone rcd still running at drop; killing it");
+        if let Err(e) = child.kill() {
+            warn!("Failed to kill managed rclone rcd process: {}", e);
+            return;
+        }
+        let _ = child.wait();
+    }
+}
 
 /// HTTP client for communicating with rclone rc daemon.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RcloneClient {
     base_url: String,
     client: Client,
+    max_retries: u32,
+    base_delay: Duration,
+    /// Shared "don't call the daemon again before this" deadline, armed by a
+    /// throttling response so every in-flight call (not just the one that
+    /// saw the response) backs off.
+    backoff_until: Arc<Mutex<Option<Instant>>>,
+    /// Basic-auth credentials attached to every request, set when the
+    /// daemon (managed or externally launched) was started with
+    /// `--rc-user`/`--rc-pass`.
+    basic_auth: Option<(String, String)>,
+    /// The daemon this client spawned, if any. `None` when pointed at an
+    /// already-running daemon via `new`/`with_retry_config`.
+    managed_daemon: Option<Arc<ManagedDaemon>>,
 }
 
 impl RcloneClient {
-    /// Create a new RcloneClient.
+    /// Create a new RcloneClient with the default retry behavior (3
+    /// retries, 250ms base delay).
     ///
     /// # Arguments
     /// * `host` - Host address of rclone daemon (e.g., "localhost")
     /// * `port` - Port number of rclone daemon (e.g., 5572)
     pub fn new(host: &str, port: u16) -> Self {
+        Self::with_retry_config(host, port, DEFAULT_MAX_RETRIES, DEFAULT_BASE_DELAY)
+    }
+
+    /// Create a new RcloneClient with custom retry behavior.
+    ///
+    /// # Arguments
+    /// * `host` - Host address of rclone daemon (e.g., "localhost")
+    /// * `port` - Port number of rclone daemon (e.g., 5572)
+    /// * `max_retries` - Maximum retry attempts for a transient failure
+    /// * `base_delay` - Delay before the first retry, doubled on each attempt
+    pub fn with_retry_config(host: &str, port: u16, max_retries: u32, base_delay: Duration) -> Self {
         let base_url = format!("http://{}:{}", host, port);
         trace!("Creating RcloneClient with base URL: {}", base_url);
         Self {
             base_url,
             client: Client::new(),
+            max_retries,
+            base_delay,
+            backoff_until: Arc::new(Mutex::new(None)),
+            basic_auth: None,
+            managed_daemon: None,
+        }
+    }
+
+    /// Attach basic-auth credentials to every request this client makes,
+    /// for an already-running daemon that was secured with
+    /// `--rc-user`/`--rc-pass` rather than one LazyFile spawned itself.
+    #[must_use]
+    pub fn with_basic_auth(mut self, rc_user: impl Into<String>, rc_pass: impl Into<String>) -> Self {
+        self.basic_auth = Some((rc_user.into(), rc_pass.into()));
+        self
+    }
+
+    /// Spawn a managed `rclone rcd` daemon on `host:port`, secured with
+    /// `rc_user`/`rc_pass` basic auth, and wait for it to start answering
+    /// requests before returning a ready client.
+    ///
+    /// Use this instead of `new`/`with_retry_config` when LazyFile should
+    /// own the daemon's lifecycle rather than assume one is already
+    /// running. The spawned process is killed once the last clone of the
+    /// returned client is dropped.
+    ///
+    /// # Errors
+    /// Returns `LazyFileError::Io` if `rclone` can't be spawned, or
+    /// `LazyFileError::RetriesExhausted` if the daemon never answers.
+    pub async fn spawn_managed(host: &str, port: u16, rc_user: &str, rc_pass: &str) -> Result<Self> {
+        let rc_addr = format!("{}:{}", host, port);
+        debug!("Spawning managed rclone rcd on {}", rc_addr);
+
+        let child = Command::new("rclone")
+            .args([
+                "rcd",
+                "--rc-addr",
+                &rc_addr,
+                "--rc-user",
+                rc_user,
+                "--rc-pass",
+                rc_pass,
+            ])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        let mut client = Self::with_retry_config(host, port, DEFAULT_MAX_RETRIES, DEFAULT_BASE_DELAY)
+            .with_basic_auth(rc_user, rc_pass);
+        client.managed_daemon = Some(Arc::new(ManagedDaemon(Mutex::new(child))));
+
+        client.wait_until_ready().await?;
+        Ok(client)
+    }
+
+    /// Poll the daemon with a fixed number of short-delay attempts until it
+    /// answers successfully. Deliberately bypasses `post`'s own
+    /// retry/backoff machinery, which is tuned for steady-state requests
+    /// rather than "has the process even finished booting yet".
+    ///
+    /// # Errors
+    /// Returns `LazyFileError::RetriesExhausted` if the daemon hasn't
+    /// answered after `READY_ATTEMPTS` attempts.
+    async fn wait_until_ready(&self) -> Result<()> {
+        const READY_ATTEMPTS: u32 = 10;
+        const READY_DELAY: Duration = Duration::from_millis(200);
+
+        let url = format!("{}/{}", self.base_url, commands::CORE_PID);
+        for attempt in 0..READY_ATTEMPTS {
+            let mut request = self.client.post(&url);
+            if let Some((user, pass)) = &self.basic_auth {
+                request = request.basic_auth(user, Some(pass));
+            }
+
+            match request.send().await {
+                Ok(response) if response.status().is_success() => {
+                    debug!("rclone rcd ready after {} attempt(s)", attempt + 1);
+                    return Ok(());
+                }
+                Ok(response) => trace!("rcd not ready yet: {}", response.status()),
+                Err(e) => trace!("rcd not ready yet: {}", e),
+            }
+            tokio::time::sleep(READY_DELAY).await;
+        }
+
+        Err(LazyFileError::RetriesExhausted(format!(
+            "rclone rcd at {} never became ready",
+            self.base_url
+        )))
+    }
+
+    /// Ask a managed `rclone rcd` daemon to quit gracefully via `core/quit`,
+    /// then wait briefly for the child process to actually exit. A no-op if
+    /// this client didn't spawn a daemon (`managed_daemon` is `None`).
+    ///
+    /// This is the intended shutdown path; call it before the last clone of
+    /// a managed client is dropped. `Drop` can't await a response, so it
+    /// only kills the process outright, and only if it's still running by
+    /// the time this wasn't called (or didn't finish in time).
+    pub async fn shutdown_managed_daemon(&self) {
+        let Some(managed) = &self.managed_daemon else {
+            return;
+        };
+
+        debug!("Asking managed rclone rcd to quit");
+        if let Err(e) = self.post(commands::CORE_QUIT, None).await {
+            warn!(
+                "core/quit request failed, falling back to killing the process: {}",
+                e
+            );
+            return;
+        }
+
+        const SHUTDOWN_ATTEMPTS: u32 = 10;
+        const SHUTDOWN_DELAY: Duration = Duration::from_millis(200);
+        for _ in 0..SHUTDOWN_ATTEMPTS {
+            if managed.has_exited() {
+                debug!("Managed rclone rcd exited cleanly");
+                return;
+            }
+            tokio::time::sleep(SHUTDOWN_DELAY).await;
+        }
+        warn!("Managed rclone rcd didn't exit in time after core/quit; Drop will kill it");
+    }
+
+    /// Build the `fs_path` (e.g. `myremote:some/dir`) rclone expects for a
+    /// `(remote, path)` pair's `fs` request field.
+    fn fs_path(remote: &str, path: &str) -> String {
+        if path.is_empty() {
+            format!("{}:", remote)
+        } else {
+            format!("{}:{}", remote, path)
+        }
+    }
+
+    /// POST to `method` with an optional JSON `body`, retrying transient
+    /// failures (connection errors, 429, 503) with exponential backoff.
+    ///
+    /// Honors `Retry-After` (delta-seconds form) and `Backoff` response
+    /// headers when present, and arms `backoff_until` so concurrent calls on
+    /// this client also wait out a throttling response rather than piling on.
+    ///
+    /// # Errors
+    /// Returns `LazyFileError::RetriesExhausted` once a transient failure is
+    /// still seen after `max_retries` retries (`max_retries + 1` attempts in
+    /// all), or the error immediately for anything else.
+    async fn post(&self, method: &str, body: Option<&serde_json::Value>) -> Result<Response> {
+        let url = format!("{}/{}", self.base_url, method);
+        let mut attempt = 0u32;
+
+        loop {
+            self.wait_out_armed_backoff().await;
+
+            let mut request = self.client.post(&url);
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+            if let Some((user, pass)) = &self.basic_auth {
+                request = request.basic_auth(user, Some(pass));
+            }
+
+            match request.send().await {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) if is_retryable_status(response.status()) => {
+                    let status = response.status();
+                    let header_delay = retry_delay_from_headers(&response);
+
+                    if attempt >= self.max_retries {
+                        return Err(LazyFileError::RetriesExhausted(format!(
+                            "{} still returning {} after {} attempts",
+                            method,
+                            status,
+                            attempt + 1
+                        )));
+                    }
+
+                    let delay = header_delay.unwrap_or_else(|| self.backoff_delay(attempt));
+                    warn!(
+                        "{} returned {}, retrying in {:?} (attempt {}/{})",
+                        method,
+                        status,
+                        delay,
+                        attempt + 1,
+                        self.max_retries
+                    );
+                    self.arm_backoff(delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Ok(response) => {
+                    error!("{} failed: {}", method, response.status());
+                    let status = response.status();
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(LazyFileError::RcloneApi(format!(
+                        "{} failed: {} {}",
+                        method, status, body
+                    )));
+                }
+                Err(e) if e.is_connect() || e.is_timeout() => {
+                    if attempt >= self.max_retries {
+                        return Err(LazyFileError::RetriesExhausted(format!(
+                            "{} unreachable after {} attempts: {}",
+                            method,
+                            attempt + 1,
+                            e
+                        )));
+                    }
+                    let delay = self.backoff_delay(attempt);
+                    warn!(
+                        "{} connection error, retrying in {:?} (attempt {}/{}): {}",
+                        method,
+                        delay,
+                        attempt + 1,
+                        self.max_retries,
+                        e
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(LazyFileError::Http(e)),
+            }
+        }
+    }
+
+    /// Exponential backoff for `attempt` (0-indexed), doubling `base_delay`
+    /// each time and capping at `MAX_BACKOFF`, with a little jitter so
+    /// concurrent callers don't retry in lockstep.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay.saturating_mul(1 << attempt.min(8));
+        jittered(exp.min(MAX_BACKOFF))
+    }
+
+    /// Arm the shared backoff deadline at least `delay` into the future.
+    fn arm_backoff(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        let mut guard = self.backoff_until.lock().unwrap();
+        if guard.is_none_or(|existing| until > existing) {
+            *guard = Some(until);
+        }
+    }
+
+    /// Sleep until any armed backoff deadline has passed.
+    async fn wait_out_armed_backoff(&self) {
+        let remaining = {
+            let guard = self.backoff_until.lock().unwrap();
+            guard.map(|until| until.saturating_duration_since(Instant::now()))
+        };
+        if let Some(remaining) = remaining {
+            if !remaining.is_zero() {
+                tokio::time::sleep(remaining).await;
+            }
         }
     }
 
@@ -36,17 +361,7 @@ impl RcloneClient {
     /// Returns error if rclone daemon is unreachable or responds with error.
     pub async fn list_remotes(&self) -> Result<Vec<String>> {
         debug!("Listing remotes");
-        let url = format!("{}/config/listremotes", self.base_url);
-
-        let response = self.client.post(&url).send().await?;
-
-        if !response.status().is_success() {
-            error!("Failed to list remotes: {}", response.status());
-            return Err(LazyFileError::RcloneApi(format!(
-                "Failed to list remotes: {}",
-                response.status()
-            )));
-        }
+        let response = self.post(commands::LIST_REMOTES, None).await?;
 
         let body = response.text().await?;
         trace!("Response body: {}", body);
@@ -74,33 +389,19 @@ impl RcloneClient {
     /// # Errors
     /// Returns error if rclone daemon is unreachable or responds with error.
     pub async fn list_files(&self, remote: &str, path: &str) -> Result<Vec<FileItem>> {
-        let fs_path = if path.is_empty() {
-            format!("{}:", remote)
-        } else {
-            format!("{}:{}", remote, path)
-        };
+        let fs_path = Self::fs_path(remote, path);
 
         debug!("Listing files in {}", fs_path);
-        let url = format!("{}/operations/list", self.base_url);
-
         let response = self
-            .client
-            .post(&url)
-            .json(&serde_json::json!({
-                "fs": fs_path,
-                "remote": ""
-            }))
-            .send()
+            .post(
+                commands::LIST_FILES,
+                Some(&serde_json::json!({
+                    "fs": fs_path,
+                    "remote": ""
+                })),
+            )
             .await?;
 
-        if !response.status().is_success() {
-            error!("Failed to list files: {}", response.status());
-            return Err(LazyFileError::RcloneApi(format!(
-                "Failed to list files: {}",
-                response.status()
-            )));
-        }
-
         let body = response.text().await?;
         trace!("Response body: {}", body);
         let json: serde_json::Value = serde_json::from_str(&body)?;
@@ -131,23 +432,14 @@ impl RcloneClient {
         parameters: HashMap<String, String>,
     ) -> Result<()> {
         debug!("Creating remote: {} (type: {})", name, remote_type);
-        let url = format!("{}/config/create", self.base_url);
         let request = ConfigCreateRequest {
             name: name.to_string(),
             remote_type: remote_type.to_string(),
             parameters,
         };
 
-        let response = self.client.post(&url).json(&request).send().await?;
-
-        if !response.status().is_success() {
-            error!("Failed to create remote: {}", response.status());
-            let body = response.text().await?;
-            return Err(LazyFileError::RcloneApi(format!(
-                "Failed to create remote: {}",
-                body
-            )));
-        }
+        self.post(commands::CONFIG_CREATE, Some(&serde_json::to_value(request)?))
+            .await?;
 
         debug!("Remote '{}' created successfully", name);
         Ok(())
@@ -167,22 +459,13 @@ impl RcloneClient {
         parameters: HashMap<String, String>,
     ) -> Result<()> {
         debug!("Updating remote: {}", name);
-        let url = format!("{}/config/update", self.base_url);
         let request = ConfigUpdateRequest {
             name: name.to_string(),
             parameters,
         };
 
-        let response = self.client.post(&url).json(&request).send().await?;
-
-        if !response.status().is_success() {
-            error!("Failed to update remote: {}", response.status());
-            let body = response.text().await?;
-            return Err(LazyFileError::RcloneApi(format!(
-                "Failed to update remote: {}",
-                body
-            )));
-        }
+        self.post(commands::CONFIG_UPDATE, Some(&serde_json::to_value(request)?))
+            .await?;
 
         debug!("Remote '{}' updated successfully", name);
         Ok(())
@@ -197,23 +480,195 @@ impl RcloneClient {
     /// Returns error if rclone daemon is unreachable or responds with error.
     pub async fn delete_remote(&self, name: &str) -> Result<()> {
         debug!("Deleting remote: {}", name);
-        let url = format!("{}/config/delete", self.base_url);
         let request = ConfigDeleteRequest {
             name: name.to_string(),
         };
 
-        let response = self.client.post(&url).json(&request).send().await?;
+        self.post(commands::CONFIG_DELETE, Some(&serde_json::to_value(request)?))
+            .await?;
+
+        debug!("Remote '{}' deleted successfully", name);
+        Ok(())
+    }
+
+    /// Fetch the option schema for every backend provider rclone supports,
+    /// used to build the dynamic remote-creation form.
+    ///
+    /// # Errors
+    /// Returns error if rclone daemon is unreachable or responds with error.
+    pub async fn providers(&self) -> Result<Vec<Provider>> {
+        debug!("Fetching provider schema");
+        let response = self.post(commands::CONFIG_PROVIDERS, None).await?;
+
+        let body = response.text().await?;
+        trace!("Response body: {}", body);
+        let parsed: ProvidersResponse = serde_json::from_str(&body)?;
+        Ok(parsed.providers)
+    }
+
+    /// Submit an rclone operation to run asynchronously.
+    ///
+    /// Adds `_async: true` to `body` and returns the resulting jobid, or
+    /// `None` if the daemon completed the request synchronously (some
+    /// builds of rclone ignore `_async` for certain methods).
+    ///
+    /// # Arguments
+    /// * `method` - Rclone rc method path (e.g. `operations/copyfile`)
+    /// * `body` - JSON request body, without the `_async` field
+    /// * `group` - If set, adds `_group` so the job's stats can be queried
+    ///   independently of other concurrent jobs via `core_stats`
+    ///
+    /// # Errors
+    /// Returns error if rclone daemon is unreachable or responds with error.
+    pub async fn submit_async(
+        &self,
+        method: &str,
+        mut body: serde_json::Value,
+        group: Option<&str>,
+    ) -> Result<Option<u64>> {
+        if let Some(obj) = body.as_object_mut() {
+            obj.insert("_async".to_string(), serde_json::Value::Bool(true));
+            if let Some(group) = group {
+                obj.insert(
+                    "_group".to_string(),
+                    serde_json::Value::String(group.to_string()),
+                );
+            }
+        }
+
+        debug!("Submitting async job: {}", method);
+        let response = self.post(method, Some(&body)).await?;
+
+        let body = response.text().await?;
+        trace!("Response body: {}", body);
+        let json: serde_json::Value = serde_json::from_str(&body)?;
 
-        if !response.status().is_success() {
-            error!("Failed to delete remote: {}", response.status());
-            let body = response.text().await?;
-            return Err(LazyFileError::RcloneApi(format!(
-                "Failed to delete remote: {}",
-                body
-            )));
+        if let Some(jobid) = json.get("jobid").and_then(serde_json::Value::as_u64) {
+            debug!("Job {} submitted as jobid {}", method, jobid);
+            return Ok(Some(jobid));
         }
 
-        debug!("Remote '{}' deleted successfully", name);
+        debug!("Daemon ran {} synchronously (no jobid returned)", method);
+        Ok(None)
+    }
+
+    /// Poll the status of a previously submitted async job.
+    ///
+    /// # Errors
+    /// Returns error if rclone daemon is unreachable or responds with error.
+    pub async fn job_status(&self, jobid: u64) -> Result<JobStatusResponse> {
+        let response = self
+            .post(
+                commands::JOB_STATUS,
+                Some(&serde_json::json!({ "jobid": jobid })),
+            )
+            .await?;
+
+        let body = response.text().await?;
+        trace!("Response body: {}", body);
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Ask the daemon to stop a previously submitted async job.
+    ///
+    /// # Errors
+    /// Returns error if rclone daemon is unreachable or responds with error.
+    pub async fn job_stop(&self, jobid: u64) -> Result<()> {
+        self.post(
+            commands::JOB_STOP,
+            Some(&serde_json::json!({ "jobid": jobid })),
+        )
+        .await?;
+        debug!("Requested stop for job {}", jobid);
         Ok(())
     }
+
+    /// Fetch aggregate transfer statistics, optionally scoped to a stats group.
+    ///
+    /// # Errors
+    /// Returns error if rclone daemon is unreachable or responds with error.
+    pub async fn core_stats(&self, group: Option<&str>) -> Result<CoreStatsResponse> {
+        let mut body = serde_json::Map::new();
+        if let Some(group) = group {
+            body.insert(
+                "group".to_string(),
+                serde_json::Value::String(group.to_string()),
+            );
+        }
+
+        let response = self
+            .post(
+                commands::CORE_STATS,
+                Some(&serde_json::Value::Object(body)),
+            )
+            .await?;
+
+        let body = response.text().await?;
+        trace!("Response body: {}", body);
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Fetch up to `max_bytes` of a file's contents for previewing.
+    ///
+    /// # Arguments
+    /// * `remote` - Name of the remote
+    /// * `path` - Path to the file within the remote
+    /// * `max_bytes` - Maximum number of bytes to fetch
+    ///
+    /// # Errors
+    /// Returns error if rclone daemon is unreachable or responds with error.
+    pub async fn cat_file(&self, remote: &str, path: &str, max_bytes: usize) -> Result<Vec<u8>> {
+        let fs_path = format!("{}:{}", remote, path.trim_start_matches('/'));
+        debug!("Fetching preview bytes for {}", fs_path);
+
+        let response = self
+            .post(
+                commands::CORE_COMMAND,
+                Some(&serde_json::json!({
+                    "command": "cat",
+                    "arg": [fs_path],
+                    "opt": { "count": max_bytes.to_string() }
+                })),
+            )
+            .await?;
+
+        let body = response.text().await?;
+        trace!("Response body: {}", body);
+        let parsed: CoreCommandResponse = serde_json::from_str(&body)?;
+        let mut bytes = parsed.result.into_bytes();
+        bytes.truncate(max_bytes);
+        Ok(bytes)
+    }
+}
+
+/// Whether `status` indicates a transient failure worth retrying.
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE
+}
+
+/// Read a retry delay from a response's `Retry-After` (delta-seconds form
+/// only) or `Backoff` (seconds) header, whichever is present.
+///
+/// The HTTP-date form of `Retry-After` isn't handled, since parsing it
+/// properly would need a date-parsing dependency this crate doesn't
+/// otherwise use; rclone's daemon only ever sends the delta-seconds form in
+/// practice.
+fn retry_delay_from_headers(response: &Response) -> Option<Duration> {
+    let seconds = response
+        .headers()
+        .get("Retry-After")
+        .or_else(|| response.headers().get("Backoff"))
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| s.parse::<u64>().ok())?;
+    Some(Duration::from_secs(seconds))
+}
+
+/// Add a small pseudo-random jitter (0-99ms) to `base`, derived from the
+/// current time rather than pulling in a `rand` dependency for this one use.
+fn jittered(base: Duration) -> Duration {
+    let jitter_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| u64::from(d.subsec_nanos()) % 100)
+        .unwrap_or(0);
+    base + Duration::from_millis(jitter_ms)
 }
@@ -4,10 +4,24 @@
 pub const LIST_REMOTES: &str = "config/listremotes";
 /// List files in a path.
 pub const LIST_FILES: &str = "operations/list";
+/// Create a new remote configuration.
+pub const CONFIG_CREATE: &str = "config/create";
+/// Update an existing remote configuration.
+pub const CONFIG_UPDATE: &str = "config/update";
+/// Delete a remote configuration.
+pub const CONFIG_DELETE: &str = "config/delete";
+/// List every backend provider and the options it accepts.
+pub const CONFIG_PROVIDERS: &str = "config/providers";
+/// Check that the rcd daemon is alive and ready to serve requests.
+pub const CORE_PID: &str = "core/pid";
+/// Ask the rcd daemon to shut down gracefully.
+pub const CORE_QUIT: &str = "core/quit";
 /// Create a directory.
 pub const MKDIR: &str = "operations/mkdir";
 /// Delete a file.
 pub const DELETE_FILE: &str = "operations/deletefile";
+/// Delete an empty directory.
+pub const RMDIR: &str = "operations/rmdir";
 /// Delete a directory and contents.
 pub const PURGE: &str = "operations/purge";
 /// Copy a file.
@@ -16,3 +30,13 @@ pub const COPY_FILE: &str = "operations/copyfile";
 pub const MOVE_FILE: &str = "operations/movefile";
 /// Sync/copy a directory.
 pub const SYNC_COPY: &str = "sync/copy";
+/// Sync/move a directory.
+pub const SYNC_MOVE: &str = "sync/move";
+/// Poll the status of an async job.
+pub const JOB_STATUS: &str = "job/status";
+/// Stop a running async job.
+pub const JOB_STOP: &str = "job/stop";
+/// Fetch aggregate transfer statistics.
+pub const CORE_STATS: &str = "core/stats";
+/// Run an rclone CLI command (used for `cat` previews).
+pub const CORE_COMMAND: &str = "core/command";
@@ -1,7 +1,10 @@
 //! Rclone JSON-RPC API client and types.
 
+pub mod cache;
 pub mod client;
+pub mod commands;
 pub mod types;
 
+pub use cache::ListingCache;
 pub use client::RcloneClient;
 pub use types::*;
@@ -42,6 +42,61 @@ pub struct ConfigDeleteRequest {
     pub name: String,
 }
 
+/// A single configurable option exposed by an rclone backend provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderOption {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Help", default)]
+    pub help: String,
+    #[serde(rename = "Default", default)]
+    pub default: Option<serde_json::Value>,
+    #[serde(rename = "Required", default)]
+    pub required: bool,
+    #[serde(rename = "IsPassword", default)]
+    pub is_password: bool,
+    #[serde(rename = "Examples", default)]
+    pub examples: Vec<ProviderOptionExample>,
+}
+
+impl ProviderOption {
+    /// Render `default` as a display string suitable for prefilling a field,
+    /// or an empty string if there is no default (or it's JSON `null`).
+    pub fn default_str(&self) -> String {
+        match &self.default {
+            Some(serde_json::Value::String(s)) => s.clone(),
+            Some(serde_json::Value::Null) | None => String::new(),
+            Some(other) => other.to_string(),
+        }
+    }
+}
+
+/// A suggested value for a `ProviderOption`, shown to help the user pick.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderOptionExample {
+    #[serde(rename = "Value", default)]
+    pub value: String,
+    #[serde(rename = "Help", default)]
+    pub help: String,
+}
+
+/// A backend provider (e.g. `s3`, `sftp`) and the options it accepts,
+/// returned by `config/providers`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Provider {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Options", default)]
+    pub options: Vec<ProviderOption>,
+}
+
+/// Response from rclone `config/providers` call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProvidersResponse {
+    #[serde(default)]
+    pub providers: Vec<Provider>,
+}
+
 /// Represents a file or directory from rclone.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileItem {
@@ -66,6 +121,65 @@ pub struct ListFilesResponse {
     pub list: Option<Vec<FileItem>>,
 }
 
+/// Response from rclone `job/status` call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobStatusResponse {
+    /// True once the job has stopped running.
+    pub finished: bool,
+    /// True if the job finished without error.
+    pub success: bool,
+    /// Error message, empty if `success` is true.
+    #[serde(default)]
+    pub error: String,
+    /// How long the job has been running, in seconds.
+    #[serde(default)]
+    pub duration: f64,
+}
+
+/// A single in-flight transfer reported by `core/stats`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransferStat {
+    /// Name of the file being transferred.
+    pub name: String,
+    /// Bytes transferred so far.
+    #[serde(default)]
+    pub bytes: i64,
+    /// Total size of the file being transferred.
+    #[serde(default)]
+    pub size: i64,
+    /// Current transfer speed in bytes/sec.
+    #[serde(default)]
+    pub speed: f64,
+}
+
+/// Response from rclone `core/stats` call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CoreStatsResponse {
+    /// Bytes transferred so far across all active transfers.
+    #[serde(default)]
+    pub bytes: i64,
+    /// Total bytes expected across all active transfers.
+    #[serde(default, rename = "totalBytes")]
+    pub total_bytes: i64,
+    /// Aggregate transfer speed in bytes/sec.
+    #[serde(default)]
+    pub speed: f64,
+    /// Files currently being transferred.
+    #[serde(default)]
+    pub transferring: Vec<TransferStat>,
+}
+
+/// Response from rclone `core/command` call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CoreCommandResponse {
+    /// Captured stdout of the command.
+    #[serde(default)]
+    pub result: String,
+    /// Captured stderr of the command, if any.
+    #[serde(default)]
+    pub error: String,
+}
+
 /// Navigation item in the file browser.
 #[derive(Debug, Clone)]
 pub enum NavigationItem {
@@ -87,6 +201,20 @@ impl NavigationItem {
             NavigationItem::File(item) => item.is_dir,
         }
     }
+
+    /// Get the size in bytes (0 for directories).
+    pub fn size(&self) -> i64 {
+        match self {
+            NavigationItem::File(item) => item.size,
+        }
+    }
+
+    /// Get the raw, rclone-formatted modification time.
+    pub fn mod_time(&self) -> &str {
+        match self {
+            NavigationItem::File(item) => &item.mod_time,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -212,4 +340,77 @@ mod tests {
         assert_eq!(req.name, "myremote");
         assert_eq!(req.parameters.get("path").unwrap(), "/newpath");
     }
+
+    #[test]
+    fn test_providers_response_parses_pascal_case() {
+        let body = serde_json::json!({
+            "providers": [{
+                "Name": "s3",
+                "Options": [{
+                    "Name": "access_key_id",
+                    "Help": "AWS Access Key ID",
+                    "Required": true,
+                    "IsPassword": false,
+                }],
+            }],
+        });
+
+        let response: ProvidersResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(response.providers.len(), 1);
+        assert_eq!(response.providers[0].name, "s3");
+        assert_eq!(response.providers[0].options[0].name, "access_key_id");
+        assert!(response.providers[0].options[0].required);
+    }
+
+    #[test]
+    fn test_provider_option_defaults() {
+        let body = serde_json::json!({ "Name": "path" });
+        let option: ProviderOption = serde_json::from_value(body).unwrap();
+
+        assert_eq!(option.name, "path");
+        assert!(option.help.is_empty());
+        assert!(option.default.is_none());
+        assert!(!option.required);
+        assert!(!option.is_password);
+        assert!(option.examples.is_empty());
+        assert_eq!(option.default_str(), "");
+    }
+
+    #[test]
+    fn test_provider_option_default_str_variants() {
+        let string_default = ProviderOption {
+            name: "region".to_string(),
+            help: String::new(),
+            default: Some(serde_json::Value::String("us-east-1".to_string())),
+            required: false,
+            is_password: false,
+            examples: Vec::new(),
+        };
+        assert_eq!(string_default.default_str(), "us-east-1");
+
+        let bool_default = ProviderOption {
+            name: "acl".to_string(),
+            help: String::new(),
+            default: Some(serde_json::Value::Bool(true)),
+            required: false,
+            is_password: false,
+            examples: Vec::new(),
+        };
+        assert_eq!(bool_default.default_str(), "true");
+    }
+
+    #[test]
+    fn test_provider_option_parses_default_and_examples() {
+        let body = serde_json::json!({
+            "Name": "region",
+            "Default": "us-east-1",
+            "Examples": [{ "Value": "us-east-1", "Help": "US East (N. Virginia)" }],
+        });
+        let option: ProviderOption = serde_json::from_value(body).unwrap();
+
+        assert_eq!(option.default_str(), "us-east-1");
+        assert_eq!(option.examples.len(), 1);
+        assert_eq!(option.examples[0].value, "us-east-1");
+        assert_eq!(option.examples[0].help, "US East (N. Virginia)");
+    }
 }
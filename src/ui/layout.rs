@@ -8,8 +8,11 @@ pub struct Layout;
 impl Layout {
     /// Split terminal area into help, content, and status regions.
     ///
+    /// When `show_preview` is false, the left/right panes split the content
+    /// area evenly and the returned `preview` rect is zero-width.
+    ///
     /// Returns `LayoutRects` containing areas for each panel.
-    pub fn split(area: Rect) -> LayoutRects {
+    pub fn split(area: Rect, show_preview: bool) -> LayoutRects {
         let chunks = ratatui::layout::Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -23,15 +26,30 @@ impl Layout {
         let content_area = chunks[1];
         let status_area = chunks[2];
 
+        let constraints = if show_preview {
+            [
+                Constraint::Percentage(30),
+                Constraint::Percentage(30),
+                Constraint::Percentage(40),
+            ]
+        } else {
+            [
+                Constraint::Percentage(50),
+                Constraint::Percentage(50),
+                Constraint::Percentage(0),
+            ]
+        };
+
         let content_chunks = ratatui::layout::Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+            .constraints(constraints)
             .split(content_area);
 
         LayoutRects {
             help: help_area,
-            remotes: content_chunks[0],
-            files: content_chunks[1],
+            left: content_chunks[0],
+            right: content_chunks[1],
+            preview: content_chunks[2],
             status: status_area,
         }
     }
@@ -41,10 +59,12 @@ impl Layout {
 pub struct LayoutRects {
     /// Help text area at top.
     pub help: Rect,
-    /// Remotes list area (left).
-    pub remotes: Rect,
-    /// Files list area (right).
-    pub files: Rect,
+    /// Left navigation pane area.
+    pub left: Rect,
+    /// Right navigation pane area.
+    pub right: Rect,
+    /// Preview pane area.
+    pub preview: Rect,
     /// Status bar area at bottom.
     pub status: Rect,
 }
@@ -0,0 +1,119 @@
+//! Bookmarks popup widget.
+
+use crate::app::bookmarks::Bookmarks;
+use crate::ui::styles;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Modal state for browsing and jumping to bookmarks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BookmarksModal {
+    /// Index of the highlighted bookmark.
+    pub selected: usize,
+}
+
+impl BookmarksModal {
+    /// Create a new bookmarks modal with the first entry highlighted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the highlight down, wrapping around `len` entries.
+    pub fn next(&mut self, len: usize) {
+        if len > 0 {
+            self.selected = (self.selected + 1) % len;
+        }
+    }
+
+    /// Move the highlight up, wrapping around `len` entries.
+    pub fn prev(&mut self, len: usize) {
+        if len > 0 {
+            self.selected = (self.selected + len - 1) % len;
+        }
+    }
+}
+
+/// Widget that lists bookmarked locations in a centered overlay.
+pub struct BookmarksWidget;
+
+impl BookmarksWidget {
+    /// Render the bookmarks popup.
+    pub fn render(f: &mut Frame, area: Rect, bookmarks: &Bookmarks, modal: &BookmarksModal) {
+        f.render_widget(Clear, area);
+        f.render_widget(
+            Block::default().style(Style::default().bg(Color::DarkGray)),
+            area,
+        );
+
+        let marks: Vec<(&str, &str)> = bookmarks.iter().collect();
+        let modal_width = 54.min(area.width.saturating_sub(4));
+        let modal_height = (marks.len() as u16 + 4)
+            .min(area.height.saturating_sub(2))
+            .max(5);
+        let x = (area.width.saturating_sub(modal_width)) / 2 + area.x;
+        let y = (area.height.saturating_sub(modal_height)) / 2 + area.y;
+        let modal_area = Rect {
+            x,
+            y,
+            width: modal_width,
+            height: modal_height,
+        };
+
+        f.render_widget(Clear, modal_area);
+        let block = Block::default()
+            .title(" Bookmarks ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(modal_area);
+        f.render_widget(block, modal_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let items: Vec<ListItem> = if marks.is_empty() {
+            vec![ListItem::new("No bookmarks yet — press 'B' to add one")]
+        } else {
+            marks
+                .iter()
+                .map(|(name, location)| ListItem::new(format!("{} -> {}", name, location)))
+                .collect()
+        };
+
+        let list = List::new(items).highlight_style(styles::selected_style());
+        let mut state = ListState::default();
+        if !marks.is_empty() {
+            state.select(Some(modal.selected.min(marks.len() - 1)));
+        }
+        f.render_stateful_widget(list, chunks[0], &mut state);
+
+        let help = Paragraph::new("j/k: Navigate | Enter: Jump | Esc: Close")
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(help, chunks[1]);
+    }
+
+    /// Render the single-line prompt shown while naming a new bookmark.
+    pub fn render_name_prompt(f: &mut Frame, area: Rect, name: &str) {
+        let width = 40.min(area.width.saturating_sub(4));
+        let x = (area.width.saturating_sub(width)) / 2 + area.x;
+        let y = area.height / 2 + area.y;
+        let prompt_area = Rect {
+            x,
+            y,
+            width,
+            height: 3,
+        };
+
+        f.render_widget(Clear, prompt_area);
+        let block = Block::default()
+            .title(" Bookmark name ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(prompt_area);
+        f.render_widget(block, prompt_area);
+        f.render_widget(Paragraph::new(name), inner);
+    }
+}
@@ -1,8 +1,9 @@
 //! Create/Edit remote modal widget.
 
+use crate::rclone::types::{Provider, ProviderOptionExample};
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Paragraph, Clear},
+    widgets::{Block, Borders, Clear, Paragraph},
 };
 
 /// Modal state for creating/editing remotes.
@@ -14,32 +15,49 @@ pub enum CreateRemoteMode {
     Edit,
 }
 
+/// One backend-specific configuration field, built from the provider's
+/// option schema (see `RcloneClient::providers`). Replaces what used to be
+/// a single fixed `path` field, since most providers don't take a `path`
+/// and many take several other options instead (e.g. `access_key_id`,
+/// `secret_access_key` for `s3`).
+#[derive(Debug, Clone)]
+pub struct RemoteField {
+    /// Option name as rclone expects it in the config parameters (e.g.
+    /// `access_key_id`).
+    pub key: String,
+    /// Short description shown as help text.
+    pub help: String,
+    /// Current value as typed by the user.
+    pub value: String,
+    /// Whether rclone requires this option to be set.
+    pub required: bool,
+    /// Whether the value should be masked on screen (e.g. secrets, tokens).
+    pub is_password: bool,
+}
+
 /// Create/Edit remote modal state.
 #[derive(Debug, Clone)]
 pub struct CreateRemoteModal {
     pub mode: CreateRemoteMode,
     pub name: String,
     pub remote_type: String,
-    pub path: String,
-    pub focus_field: RemoteField,
+    /// Dynamic, provider-specific fields built from the selected
+    /// `remote_type`'s option schema. Empty until `apply_provider` is
+    /// called (normally once the user tabs away from the Type field).
+    pub fields: Vec<RemoteField>,
+    /// Index into the field sequence [Name, Type, fields...].
+    pub focus: usize,
     pub error: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum RemoteField {
-    Name,
-    Type,
-    Path,
-}
-
 impl CreateRemoteModal {
     pub fn new(mode: CreateRemoteMode) -> Self {
         Self {
             mode,
             name: String::new(),
-            remote_type: String::from("local"),
-            path: String::new(),
-            focus_field: RemoteField::Name,
+            remote_type: String::new(),
+            fields: Vec::new(),
+            focus: 0,
             error: None,
         }
     }
@@ -54,46 +72,86 @@ impl CreateRemoteModal {
         self
     }
 
+    /// Total number of navigable fields: Name, Type, plus every dynamic field.
+    fn field_count(&self) -> usize {
+        self.fields.len() + 2
+    }
+
+    /// Index of the Type field within the navigable sequence.
+    pub const TYPE_FIELD: usize = 1;
+
+    /// Rebuild `fields` from a provider's option schema, carrying over any
+    /// value already typed for an option that's still present. An option
+    /// with no carried-over value is prefilled from the schema's `Default`,
+    /// and its help text is extended with any enumerated `Examples`.
+    pub fn apply_provider(&mut self, provider: &Provider) {
+        let previous = self.fields.clone();
+        self.fields = provider
+            .options
+            .iter()
+            .map(|opt| {
+                let value = previous
+                    .iter()
+                    .find(|f| f.key == opt.name)
+                    .map(|f| f.value.clone())
+                    .unwrap_or_else(|| opt.default_str());
+                RemoteField {
+                    key: opt.name.clone(),
+                    help: help_with_examples(&opt.help, &opt.examples),
+                    value,
+                    required: opt.required,
+                    is_password: opt.is_password,
+                }
+            })
+            .collect();
+        if self.focus >= self.field_count() {
+            self.focus = 0;
+        }
+    }
+
     pub fn next_field(&mut self) {
-        self.focus_field = match self.focus_field {
-            RemoteField::Name => RemoteField::Type,
-            RemoteField::Type => RemoteField::Path,
-            RemoteField::Path => RemoteField::Name,
-        };
+        self.focus = (self.focus + 1) % self.field_count();
     }
 
     pub fn prev_field(&mut self) {
-        self.focus_field = match self.focus_field {
-            RemoteField::Name => RemoteField::Path,
-            RemoteField::Type => RemoteField::Name,
-            RemoteField::Path => RemoteField::Type,
-        };
+        self.focus = (self.focus + self.field_count() - 1) % self.field_count();
     }
 
     pub fn input_char(&mut self, c: char) {
-        match self.focus_field {
-            RemoteField::Name => self.name.push(c),
-            RemoteField::Type => self.remote_type.push(c),
-            RemoteField::Path => self.path.push(c),
+        match self.focus {
+            0 => self.name.push(c),
+            Self::TYPE_FIELD => self.remote_type.push(c),
+            i => {
+                if let Some(field) = self.fields.get_mut(i - 2) {
+                    field.value.push(c);
+                }
+            }
         }
     }
 
     pub fn backspace(&mut self) {
-        match self.focus_field {
-            RemoteField::Name => {
+        match self.focus {
+            0 => {
                 self.name.pop();
             }
-            RemoteField::Type => {
+            Self::TYPE_FIELD => {
                 self.remote_type.pop();
             }
-            RemoteField::Path => {
-                self.path.pop();
+            i => {
+                if let Some(field) = self.fields.get_mut(i - 2) {
+                    field.value.pop();
+                }
             }
         }
     }
 
     pub fn is_valid(&self) -> bool {
-        !self.name.is_empty() && !self.remote_type.is_empty()
+        !self.name.is_empty()
+            && !self.remote_type.is_empty()
+            && self
+                .fields
+                .iter()
+                .all(|f| !f.required || !f.value.is_empty())
     }
 }
 
@@ -106,9 +164,9 @@ impl CreateRemoteWidget {
         f.render_widget(Clear, area);
         f.render_widget(backdrop.style(Style::default().bg(Color::DarkGray)), area);
 
-        // Calculate compact modal size (much smaller)
+        let field_rows = modal.field_count();
         let modal_width = 50.min(area.width.saturating_sub(4));
-        let modal_height = 13; // Compact: title + 3 fields + help
+        let modal_height = (field_rows as u16 * 2 + 3).min(area.height.saturating_sub(2));
         let x = (area.width.saturating_sub(modal_width)) / 2 + area.x;
         let y = (area.height.saturating_sub(modal_height)) / 2 + area.y;
 
@@ -139,42 +197,38 @@ impl CreateRemoteWidget {
             height: modal_area.height.saturating_sub(2),
         };
 
+        let mut constraints = vec![Constraint::Length(2); field_rows];
+        constraints.push(Constraint::Min(1));
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(2),
-                Constraint::Length(2),
-                Constraint::Length(2),
-                Constraint::Min(1),
-            ])
+            .constraints(constraints)
             .split(inner);
 
-        // Name field
-        Self::render_field(
-            f,
-            chunks[0],
-            "Name",
-            &modal.name,
-            modal.focus_field == RemoteField::Name,
-        );
-
-        // Type field
+        Self::render_field(f, chunks[0], "Name", &modal.name, false, modal.focus == 0);
         Self::render_field(
             f,
             chunks[1],
             "Type",
             &modal.remote_type,
-            modal.focus_field == RemoteField::Type,
+            false,
+            modal.focus == CreateRemoteModal::TYPE_FIELD,
         );
 
-        // Path field
-        Self::render_field(
-            f,
-            chunks[2],
-            "Path",
-            &modal.path,
-            modal.focus_field == RemoteField::Path,
-        );
+        for (i, field) in modal.fields.iter().enumerate() {
+            let label = if field.required {
+                format!("{}*", field.help_label())
+            } else {
+                field.help_label()
+            };
+            Self::render_field(
+                f,
+                chunks[i + 2],
+                &label,
+                &field.value,
+                field.is_password,
+                modal.focus == i + 2,
+            );
+        }
 
         // Error or help text (single line, smaller font)
         let help_text = if let Some(ref error) = modal.error {
@@ -190,17 +244,26 @@ impl CreateRemoteWidget {
         };
 
         let help = Paragraph::new(help_text).style(style);
-        f.render_widget(help, chunks[3]);
+        f.render_widget(help, chunks[field_rows]);
     }
 
-    fn render_field(f: &mut Frame, area: Rect, label: &str, value: &str, focused: bool) {
-        let value_display = if value.len() > 30 {
-            format!("{}...", &value[..27])
+    fn render_field(
+        f: &mut Frame,
+        area: Rect,
+        label: &str,
+        value: &str,
+        is_password: bool,
+        focused: bool,
+    ) {
+        let masked;
+        let shown = if is_password {
+            masked = "*".repeat(value.chars().count());
+            &masked
         } else {
-            value.to_string()
+            value
         };
 
-        let text = format!("{}: {}", label, value_display);
+        let text = format!("{}: {}", label, truncate_display(shown));
         let paragraph = Paragraph::new(text).style(if focused {
             Style::default().fg(Color::Yellow).bold()
         } else {
@@ -211,7 +274,7 @@ impl CreateRemoteWidget {
 
         // Minimal bottom border for focused field
         if focused && area.height > 1 {
-            let bottom_line = "â”€".repeat(area.width as usize);
+            let bottom_line = "─".repeat(area.width as usize);
             let bottom = Paragraph::new(bottom_line).style(Style::default().fg(Color::Cyan));
             let bottom_area = Rect {
                 y: area.y + 1,
@@ -223,18 +286,90 @@ impl CreateRemoteWidget {
     }
 }
 
+/// Truncate `value` to 30 displayed characters, appending `...` if it was
+/// longer. Truncates by `char`, not byte offset, since a byte-range slice
+/// would panic on a value with a multibyte character near the cut point.
+fn truncate_display(value: &str) -> String {
+    if value.chars().count() > 30 {
+        format!("{}...", value.chars().take(27).collect::<String>())
+    } else {
+        value.to_string()
+    }
+}
+
+/// Append a parenthesized list of example values to `help`, if there are any.
+fn help_with_examples(help: &str, examples: &[ProviderOptionExample]) -> String {
+    if examples.is_empty() {
+        return help.to_string();
+    }
+    let values: Vec<&str> = examples.iter().map(|e| e.value.as_str()).collect();
+    if help.is_empty() {
+        format!("(e.g. {})", values.join(", "))
+    } else {
+        format!("{} (e.g. {})", help, values.join(", "))
+    }
+}
+
+impl RemoteField {
+    /// Display label for this field: its help text if rclone gave one,
+    /// falling back to the raw option key.
+    fn help_label(&self) -> String {
+        if self.help.is_empty() {
+            self.key.clone()
+        } else {
+            self.help.clone()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rclone::types::ProviderOption;
+
+    fn s3_provider() -> Provider {
+        Provider {
+            name: "s3".to_string(),
+            options: vec![
+                ProviderOption {
+                    name: "access_key_id".to_string(),
+                    help: "AWS Access Key ID".to_string(),
+                    default: None,
+                    required: true,
+                    is_password: false,
+                    examples: Vec::new(),
+                },
+                ProviderOption {
+                    name: "secret_access_key".to_string(),
+                    help: "AWS Secret Access Key".to_string(),
+                    default: None,
+                    required: true,
+                    is_password: true,
+                    examples: Vec::new(),
+                },
+                ProviderOption {
+                    name: "region".to_string(),
+                    help: "Region to connect to".to_string(),
+                    default: Some(serde_json::Value::String("us-east-1".to_string())),
+                    required: false,
+                    is_password: false,
+                    examples: vec![ProviderOptionExample {
+                        value: "us-east-1".to_string(),
+                        help: "US East (N. Virginia)".to_string(),
+                    }],
+                },
+            ],
+        }
+    }
 
     #[test]
     fn test_create_remote_modal_new() {
         let modal = CreateRemoteModal::new(CreateRemoteMode::Create);
         assert_eq!(modal.mode, CreateRemoteMode::Create);
         assert!(modal.name.is_empty());
-        assert_eq!(modal.remote_type, "local");
-        assert!(modal.path.is_empty());
-        assert_eq!(modal.focus_field, RemoteField::Name);
+        assert!(modal.remote_type.is_empty());
+        assert!(modal.fields.is_empty());
+        assert_eq!(modal.focus, 0);
         assert!(modal.error.is_none());
     }
 
@@ -255,36 +390,95 @@ mod tests {
     }
 
     #[test]
-    fn test_field_navigation() {
-        let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create);
-        assert_eq!(modal.focus_field, RemoteField::Name);
+    fn test_apply_provider_builds_dynamic_fields() {
+        let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create).with_type("s3".to_string());
+        modal.apply_provider(&s3_provider());
+
+        assert_eq!(modal.fields.len(), 3);
+        assert_eq!(modal.fields[0].key, "access_key_id");
+        assert!(modal.fields[0].required);
+        assert!(!modal.fields[0].is_password);
+        assert!(modal.fields[1].is_password);
+        assert!(!modal.fields[2].required);
+    }
+
+    #[test]
+    fn test_truncate_display_short_value_unchanged() {
+        assert_eq!(truncate_display("short"), "short");
+    }
+
+    #[test]
+    fn test_truncate_display_long_ascii_value() {
+        let value = "a".repeat(40);
+        assert_eq!(truncate_display(&value), format!("{}...", "a".repeat(27)));
+    }
+
+    #[test]
+    fn test_truncate_display_does_not_panic_on_multibyte_boundary() {
+        // Each 'é' is 2 bytes, so byte offset 27 falls in the middle of a
+        // multibyte character; truncation must still succeed.
+        let value = "é".repeat(40);
+        let result = truncate_display(&value);
+        assert_eq!(result, format!("{}...", "é".repeat(27)));
+    }
+
+    #[test]
+    fn test_apply_provider_prefills_default_and_extends_help() {
+        let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create).with_type("s3".to_string());
+        modal.apply_provider(&s3_provider());
+
+        assert_eq!(modal.fields[2].value, "us-east-1");
+        assert_eq!(
+            modal.fields[2].help,
+            "Region to connect to (e.g. us-east-1)"
+        );
+        assert_eq!(modal.fields[0].help, "AWS Access Key ID");
+    }
 
-        modal.next_field();
-        assert_eq!(modal.focus_field, RemoteField::Type);
+    #[test]
+    fn test_apply_provider_preserves_existing_values() {
+        let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create).with_type("s3".to_string());
+        modal.apply_provider(&s3_provider());
+        modal.fields[0].value = "AKIA...".to_string();
 
-        modal.next_field();
-        assert_eq!(modal.focus_field, RemoteField::Path);
+        modal.apply_provider(&s3_provider());
+        assert_eq!(modal.fields[0].value, "AKIA...");
+    }
 
-        modal.next_field();
-        assert_eq!(modal.focus_field, RemoteField::Name);
+    #[test]
+    fn test_field_navigation() {
+        let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create).with_type("s3".to_string());
+        modal.apply_provider(&s3_provider());
+        assert_eq!(modal.focus, 0);
+
+        modal.next_field(); // Type
+        assert_eq!(modal.focus, 1);
+        modal.next_field(); // access_key_id
+        assert_eq!(modal.focus, 2);
+        modal.next_field(); // secret_access_key
+        assert_eq!(modal.focus, 3);
+        modal.next_field(); // region
+        assert_eq!(modal.focus, 4);
+        modal.next_field(); // wraps back to Name
+        assert_eq!(modal.focus, 0);
     }
 
     #[test]
     fn test_prev_field_navigation() {
         let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create);
-        modal.focus_field = RemoteField::Type;
+        modal.focus = 1;
 
         modal.prev_field();
-        assert_eq!(modal.focus_field, RemoteField::Name);
+        assert_eq!(modal.focus, 0);
 
         modal.prev_field();
-        assert_eq!(modal.focus_field, RemoteField::Path);
+        assert_eq!(modal.focus, 1);
     }
 
     #[test]
     fn test_input_char_to_name() {
         let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create);
-        modal.focus_field = RemoteField::Name;
+        modal.focus = 0;
 
         modal.input_char('t');
         assert_eq!(modal.name, "t");
@@ -298,18 +492,27 @@ mod tests {
     #[test]
     fn test_input_char_to_type() {
         let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create);
-        modal.focus_field = RemoteField::Type;
-        modal.remote_type.clear();
+        modal.focus = CreateRemoteModal::TYPE_FIELD;
 
         modal.input_char('s');
         modal.input_char('3');
         assert_eq!(modal.remote_type, "s3");
     }
 
+    #[test]
+    fn test_input_char_to_dynamic_field() {
+        let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create).with_type("s3".to_string());
+        modal.apply_provider(&s3_provider());
+        modal.focus = 2;
+
+        modal.input_char('x');
+        assert_eq!(modal.fields[0].value, "x");
+    }
+
     #[test]
     fn test_backspace() {
         let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create);
-        modal.focus_field = RemoteField::Name;
+        modal.focus = 0;
         modal.name = "test".to_string();
 
         modal.backspace();
@@ -322,7 +525,7 @@ mod tests {
     #[test]
     fn test_backspace_empty_string() {
         let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create);
-        modal.focus_field = RemoteField::Name;
+        modal.focus = 0;
 
         modal.backspace();
         assert!(modal.name.is_empty());
@@ -334,22 +537,23 @@ mod tests {
         assert!(!modal.is_valid()); // name is empty
 
         modal.name = "myremote".to_string();
-        assert!(modal.is_valid()); // now valid
-
-        modal.remote_type.clear();
         assert!(!modal.is_valid()); // type is empty
-    }
 
-    #[test]
-    fn test_is_valid_requires_both_fields() {
-        let modal = CreateRemoteModal::new(CreateRemoteMode::Create)
-            .with_name("myremote".to_string());
+        modal.remote_type = "s3".to_string();
         assert!(modal.is_valid());
+    }
 
-        let modal = CreateRemoteModal::new(CreateRemoteMode::Create)
+    #[test]
+    fn test_is_valid_requires_required_fields() {
+        let mut modal = CreateRemoteModal::new(CreateRemoteMode::Create)
+            .with_name("myremote".to_string())
             .with_type("s3".to_string());
-        let mut modal = modal;
-        modal.name.clear();
-        assert!(!modal.is_valid());
+        modal.apply_provider(&s3_provider());
+
+        assert!(!modal.is_valid()); // access_key_id/secret_access_key required but empty
+
+        modal.fields[0].value = "AKIA...".to_string();
+        modal.fields[1].value = "secret".to_string();
+        assert!(modal.is_valid()); // region is optional
     }
 }
@@ -1,13 +1,25 @@
 //! Files list widget.
 
+use crate::app::filter;
 use crate::rclone::NavigationItem;
 use crate::ui::styles;
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, List, ListItem},
 };
+use std::collections::HashSet;
 
-/// Widget for displaying files and directories.
+/// Width of the selection marker column, in characters.
+const MARKER_WIDTH: usize = 2;
+
+/// Width of the right-aligned size column, in characters.
+const SIZE_WIDTH: usize = 10;
+
+/// Width of the left-aligned modification time column, in characters.
+const MODTIME_WIDTH: usize = 16;
+
+/// Widget for displaying files and directories in columns: selection
+/// marker, name, size, and modification time.
 pub struct FileListWidget;
 
 impl FileListWidget {
@@ -16,28 +28,71 @@ impl FileListWidget {
     /// # Arguments
     /// * `f` - Frame for rendering
     /// * `area` - Area to render in
+    /// * `label` - Pane label shown in the title (e.g. "Left"/"Right")
     /// * `files` - List of navigation items
-    /// * `selected` - Index of selected item
+    /// * `filtered_indices` - Indices into `files` to show, already sorted, in display order
+    /// * `selected` - Index into `filtered_indices` of the selected item
+    /// * `query` - Active incremental filter query, if any
     /// * `focused` - Whether this panel is focused
+    /// * `selected_files` - Indices into `files` marked for a batch operation
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         f: &mut Frame,
         area: Rect,
+        label: &str,
         files: &[NavigationItem],
+        filtered_indices: &[usize],
         selected: usize,
+        query: Option<&str>,
         focused: bool,
+        selected_files: &HashSet<usize>,
     ) {
-        let items: Vec<ListItem> = files
+        let content_width = area.width.saturating_sub(2) as usize;
+        let name_width =
+            content_width.saturating_sub(MARKER_WIDTH + SIZE_WIDTH + MODTIME_WIDTH + 2);
+
+        let items: Vec<ListItem> = filtered_indices
             .iter()
-            .map(|item| {
-                let name = if item.is_dir() {
-                    format!("[{}]", item.name())
+            .filter_map(|&i| files.get(i).map(|item| (i, item)))
+            .map(|(i, item)| {
+                let marker = if selected_files.contains(&i) {
+                    Span::styled("* ", Style::new().fg(Color::Yellow))
                 } else {
-                    item.name().to_string()
+                    Span::raw("  ")
                 };
-                ListItem::new(name)
+                let mut spans = vec![marker];
+
+                let mut name_len = item.name().chars().count();
+                if item.is_dir() {
+                    spans.push(Span::raw("["));
+                    name_len += 2;
+                }
+                spans.extend(Self::highlighted_spans(item.name(), query));
+                if item.is_dir() {
+                    spans.push(Span::raw("]"));
+                }
+                spans.push(Span::raw(" ".repeat(name_width.saturating_sub(name_len) + 1)));
+
+                let size = if item.is_dir() {
+                    "-".to_string()
+                } else {
+                    format_size(item.size())
+                };
+                spans.push(Span::raw(format!("{size:>SIZE_WIDTH$} ")));
+                spans.push(Span::raw(format!(
+                    "{:<MODTIME_WIDTH$}",
+                    format_mod_time(item.mod_time())
+                )));
+
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
+        let title = match query {
+            Some(q) => format!(" {}: Files (/{}) ", label, q),
+            None => format!(" {}: Files ", label),
+        };
+
         let border_style = if focused {
             styles::focused_style()
         } else {
@@ -48,7 +103,7 @@ impl FileListWidget {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" Files ")
+                    .title(title)
                     .border_style(border_style),
             )
             .style(styles::NORMAL_STYLE)
@@ -59,4 +114,55 @@ impl FileListWidget {
 
         f.render_stateful_widget(list, area, &mut list_state);
     }
+
+    /// Split `text` into spans, bolding characters matched by `query`.
+    fn highlighted_spans(text: &str, query: Option<&str>) -> Vec<Span<'static>> {
+        let Some(query) = query.filter(|q| !q.is_empty()) else {
+            return vec![Span::raw(text.to_string())];
+        };
+        let Some(m) = filter::fuzzy_match(query, text) else {
+            return vec![Span::raw(text.to_string())];
+        };
+
+        text.chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if m.positions.contains(&i) {
+                    Span::styled(
+                        c.to_string(),
+                        Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Span::raw(c.to_string())
+                }
+            })
+            .collect()
+    }
+}
+
+/// Format a byte count as a human-readable string (e.g. `1.5 MiB`).
+fn format_size(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} {}", UNITS[unit])
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+/// Format an rclone `ModTime` (RFC 3339, e.g. `2024-01-01T12:34:56.789Z`)
+/// as `2024-01-01 12:34`, falling back to the raw value if it's shorter
+/// than expected.
+fn format_mod_time(raw: &str) -> String {
+    if raw.len() >= 16 {
+        raw[..16].replacen('T', " ", 1)
+    } else {
+        raw.to_string()
+    }
 }
@@ -13,7 +13,7 @@ impl HelpWidget {
     /// * `f` - Frame for rendering
     /// * `area` - Area to render in
     pub fn render(f: &mut Frame, area: Rect) {
-        let help_text = "j/k: Navigate | a: Add | e: Edit | d: Delete | Enter: Open | Backspace: Back | Tab: Panel | q: Quit";
+        let help_text = "j/k: Navigate | /: Filter | a: Add | e: Edit | d: Delete | m: Select | c: Copy | M: Move | Ctrl+A: Select All | B: Bookmark | ': Bookmarks | R: Refresh | s/S: Sort | p: Preview | x: Cancel Job | Enter: Open | Backspace: Back | Tab: Pane | q: Quit";
         let paragraph = Paragraph::new(help_text).style(styles::header_style());
         f.render_widget(paragraph, area);
     }
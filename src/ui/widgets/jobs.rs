@@ -0,0 +1,73 @@
+//! Background transfer progress widget.
+
+use crate::app::jobs::Job;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Gauge, Paragraph},
+};
+
+/// Widget for displaying in-flight background transfers.
+pub struct JobsWidget;
+
+impl JobsWidget {
+    /// Render a transfers panel listing every active job, anchored to the
+    /// bottom-right corner of `area`. Renders nothing when `jobs` is empty.
+    pub fn render(f: &mut Frame, area: Rect, jobs: &[Job]) {
+        if jobs.is_empty() {
+            return;
+        }
+
+        let width = 44.min(area.width.saturating_sub(2));
+        let height = (jobs.len() as u16 * 2 + 2).min(area.height.saturating_sub(2));
+        let x = area.x + area.width.saturating_sub(width + 1);
+        let y = area.y + area.height.saturating_sub(height + 1);
+
+        let panel_area = Rect { x, y, width, height };
+
+        f.render_widget(Clear, panel_area);
+        let block = Block::default()
+            .title(format!(" Transfers ({}) ", jobs.len()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(panel_area);
+        f.render_widget(block, panel_area);
+
+        let rows = ratatui::layout::Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(2); jobs.len()])
+            .split(inner);
+
+        for (job, row) in jobs.iter().zip(rows.iter()) {
+            let label_area = Rect {
+                height: 1,
+                ..*row
+            };
+            let gauge_area = Rect {
+                y: row.y + 1,
+                height: 1,
+                ..*row
+            };
+
+            let label = Paragraph::new(format!("{} ({})", job.description, format_speed(job.speed)));
+            f.render_widget(label, label_area);
+
+            let gauge = Gauge::default()
+                .gauge_style(Style::default().fg(Color::Cyan))
+                .ratio((job.percent() / 100.0).clamp(0.0, 1.0))
+                .label(format!("{:.0}%", job.percent()));
+            f.render_widget(gauge, gauge_area);
+        }
+    }
+}
+
+/// Format a transfer speed in bytes/sec as a human-readable string.
+fn format_speed(bytes_per_sec: f64) -> String {
+    const UNITS: [&str; 4] = ["B/s", "KiB/s", "MiB/s", "GiB/s"];
+    let mut speed = bytes_per_sec;
+    let mut unit = 0;
+    while speed >= 1024.0 && unit < UNITS.len() - 1 {
+        speed /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", speed, UNITS[unit])
+}
@@ -1,15 +1,21 @@
 //! UI widget components.
 
+pub mod bookmarks;
 pub mod create_remote;
 pub mod confirm_modal;
 pub mod file_list;
 pub mod help;
+pub mod jobs;
+pub mod preview;
 pub mod remote_list;
 pub mod status_bar;
 
+pub use bookmarks::{BookmarksModal, BookmarksWidget};
 pub use create_remote::{CreateRemoteModal, CreateRemoteWidget, CreateRemoteMode};
 pub use confirm_modal::{ConfirmModal, ConfirmWidget};
 pub use file_list::FileListWidget;
 pub use help::HelpWidget;
+pub use jobs::JobsWidget;
+pub use preview::{PreviewContent, PreviewWidget, PREVIEW_BYTE_LIMIT};
 pub use remote_list::RemoteListWidget;
 pub use status_bar::StatusBarWidget;
@@ -0,0 +1,111 @@
+//! File preview pane with syntax highlighting.
+
+use crate::ui::styles;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+};
+use std::sync::OnceLock;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Style as SynStyle, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+/// Maximum number of preview bytes fetched per file.
+pub const PREVIEW_BYTE_LIMIT: usize = 64 * 1024;
+
+/// Content currently shown in the preview pane.
+#[derive(Debug, Clone, Default)]
+pub enum PreviewContent {
+    /// No file selected, or the selection is a directory.
+    #[default]
+    Empty,
+    /// Decoded text contents, with the extension used to pick a syntax.
+    Text {
+        /// File extension (without the leading dot), used to select a syntax.
+        extension: String,
+        /// Decoded file contents, capped at `PREVIEW_BYTE_LIMIT` bytes.
+        body: String,
+    },
+    /// File looked binary; only its size is shown.
+    Binary {
+        /// Total size of the file in bytes, as reported by `operations/list`.
+        size: i64,
+    },
+}
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme_set() -> &'static ThemeSet {
+    static SET: OnceLock<ThemeSet> = OnceLock::new();
+    SET.get_or_init(ThemeSet::load_defaults)
+}
+
+/// Widget for previewing the file currently selected in the Files panel.
+pub struct PreviewWidget;
+
+impl PreviewWidget {
+    /// Render the preview pane.
+    ///
+    /// # Arguments
+    /// * `f` - Frame for rendering
+    /// * `area` - Area to render in
+    /// * `content` - Preview content to display
+    pub fn render(f: &mut Frame, area: Rect, content: &PreviewContent) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Preview ")
+            .border_style(styles::NORMAL_STYLE);
+
+        match content {
+            PreviewContent::Empty => {
+                f.render_widget(Paragraph::new("").block(block), area);
+            }
+            PreviewContent::Binary { size } => {
+                let text = format!("binary file, {} bytes", size);
+                f.render_widget(Paragraph::new(text).block(block), area);
+            }
+            PreviewContent::Text { extension, body } => {
+                let inner = block.inner(area);
+                f.render_widget(block, area);
+                let lines = Self::highlight(extension, body, inner.height as usize);
+                f.render_widget(Paragraph::new(lines), inner);
+            }
+        }
+    }
+
+    /// Highlight `body` as `extension`-flavored source, capped to `max_lines`.
+    fn highlight(extension: &str, body: &str, max_lines: usize) -> Vec<Line<'static>> {
+        let ps = syntax_set();
+        let ts = theme_set();
+        let syntax = ps
+            .find_syntax_by_extension(extension)
+            .unwrap_or_else(|| ps.find_syntax_plain_text());
+        let theme = &ts.themes["base16-ocean.dark"];
+        let mut highlighter = HighlightLines::new(syntax, theme);
+
+        LinesWithEndings::from(body)
+            .take(max_lines)
+            .map(|line| {
+                let ranges = highlighter.highlight_line(line, ps).unwrap_or_default();
+                let spans: Vec<Span<'static>> = ranges
+                    .into_iter()
+                    .map(|(style, text)| Span::styled(text.to_string(), to_ratatui_style(style)))
+                    .collect();
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+/// Convert a syntect highlighting style into a ratatui style.
+fn to_ratatui_style(style: SynStyle) -> Style {
+    Style::default().fg(Color::Rgb(
+        style.foreground.r,
+        style.foreground.g,
+        style.foreground.b,
+    ))
+}
@@ -1,5 +1,6 @@
 //! Remotes list widget.
 
+use crate::app::filter;
 use crate::ui::styles;
 use ratatui::{
     prelude::*,
@@ -15,21 +16,34 @@ impl RemoteListWidget {
     /// # Arguments
     /// * `f` - Frame for rendering
     /// * `area` - Area to render in
+    /// * `label` - Pane label shown in the title (e.g. "Left"/"Right")
     /// * `remotes` - List of remote names
-    /// * `selected` - Index of selected remote
+    /// * `filtered_indices` - Indices into `remotes` to show, in display order
+    /// * `selected` - Index into `filtered_indices` of the selected remote
+    /// * `query` - Active incremental filter query, if any
     /// * `focused` - Whether this panel is focused
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         f: &mut Frame,
         area: Rect,
+        label: &str,
         remotes: &[String],
+        filtered_indices: &[usize],
         selected: usize,
+        query: Option<&str>,
         focused: bool,
     ) {
-        let items: Vec<ListItem> = remotes
+        let items: Vec<ListItem> = filtered_indices
             .iter()
-            .map(|r| ListItem::new(r.as_str()))
+            .filter_map(|&i| remotes.get(i))
+            .map(|r| ListItem::new(Line::from(Self::highlighted_spans(r, query))))
             .collect();
 
+        let title = match query {
+            Some(q) => format!(" {}: Remotes (/{}) ", label, q),
+            None => format!(" {}: Remotes ", label),
+        };
+
         let border_style = if focused {
             styles::focused_style()
         } else {
@@ -40,7 +54,7 @@ impl RemoteListWidget {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" Remotes ")
+                    .title(title)
                     .border_style(border_style),
             )
             .style(styles::NORMAL_STYLE)
@@ -51,4 +65,28 @@ impl RemoteListWidget {
 
         f.render_stateful_widget(list, area, &mut list_state);
     }
+
+    /// Split `text` into spans, bolding characters matched by `query`.
+    fn highlighted_spans(text: &str, query: Option<&str>) -> Vec<Span<'static>> {
+        let Some(query) = query.filter(|q| !q.is_empty()) else {
+            return vec![Span::raw(text.to_string())];
+        };
+        let Some(m) = filter::fuzzy_match(query, text) else {
+            return vec![Span::raw(text.to_string())];
+        };
+
+        text.chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if m.positions.contains(&i) {
+                    Span::styled(
+                        c.to_string(),
+                        Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Span::raw(c.to_string())
+                }
+            })
+            .collect()
+    }
 }
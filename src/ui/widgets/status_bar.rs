@@ -15,8 +15,20 @@ impl StatusBarWidget {
     /// * `remote` - Currently selected remote
     /// * `path` - Current path within remote
     /// * `connected` - Connection status
-    pub fn render(f: &mut Frame, area: Rect, remote: Option<&str>, path: &str, connected: bool) {
-        let status = if connected {
+    /// * `loading` - Whether a directory listing is loading in the background
+    /// * `job_error` - Error from the most recently finished background job, if any
+    pub fn render(
+        f: &mut Frame,
+        area: Rect,
+        remote: Option<&str>,
+        path: &str,
+        connected: bool,
+        loading: bool,
+        job_error: Option<&str>,
+    ) {
+        let status = if loading {
+            "Loading…"
+        } else if connected {
             "Connected"
         } else {
             "Disconnected"
@@ -32,7 +44,11 @@ impl StatusBarWidget {
             "Select a remote".to_string()
         };
 
-        let text = format!("  {} | {}  ", display_path, status);
+        let text = if let Some(error) = job_error {
+            format!("  {} | {} | Error: {}  ", display_path, status, error)
+        } else {
+            format!("  {} | {}  ", display_path, status)
+        };
         let paragraph = Paragraph::new(text).style(styles::status_bar_style());
         f.render_widget(paragraph, area);
     }